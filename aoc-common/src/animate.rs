@@ -0,0 +1,279 @@
+// Step by step rendering for the grid simulation days. A day records the state of its grid as a
+// sequence of text frames, and those can then be played back in the terminal, or written out as
+// plain text files to diff or page through. Frames are kept as plain text, and colors are only
+// added when playing, so a dump never has escape codes in it.
+
+use crate::{Grid, Input, ParseError, Solution};
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+const CLEAR: &str = "\x1b[H\x1b[2J";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Dim,
+}
+
+impl Color {
+    fn code(self) -> &'static str {
+        match self {
+            Color::Red => "\x1b[31m",
+            Color::Green => "\x1b[32m",
+            Color::Yellow => "\x1b[33m",
+            Color::Blue => "\x1b[34m",
+            Color::Magenta => "\x1b[35m",
+            Color::Cyan => "\x1b[36m",
+            Color::Dim => "\x1b[2m",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Frame {
+    pub caption: String,
+    pub text: String,
+}
+
+/// A recorded simulation, with the color to draw each character in.
+#[derive(Clone, Debug)]
+pub struct Animation {
+    frames: Vec<Frame>,
+    palette: &'static [(char, Color)],
+}
+
+impl Animation {
+    pub fn new(palette: &'static [(char, Color)]) -> Self {
+        Self {
+            frames: Vec::new(),
+            palette,
+        }
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Records a frame made of `rows` of text.
+    pub fn push<R: fmt::Display>(
+        &mut self,
+        caption: impl Into<String>,
+        rows: impl IntoIterator<Item = R>,
+    ) {
+        let mut text = String::new();
+        for row in rows {
+            text += &format!("{row}\n");
+        }
+        self.frames.push(Frame {
+            caption: caption.into(),
+            text,
+        });
+    }
+
+    pub fn push_grid<T: fmt::Display>(&mut self, caption: impl Into<String>, grid: &Grid<T>) {
+        let rows = grid
+            .rows()
+            .map(|row| row.iter().map(|v| v.to_string()).collect::<String>());
+        self.push(caption, rows);
+    }
+
+    /// The frame at `index` with its caption, and ANSI colors if `color` is set.
+    pub fn render(&self, index: usize, color: bool) -> String {
+        let frame = &self.frames[index];
+        let mut out = format!(
+            "Frame {}/{}: {}\n",
+            index + 1,
+            self.frames.len(),
+            frame.caption
+        );
+        if !color {
+            out += &frame.text;
+            return out;
+        }
+
+        // Only switch colors between runs, which keeps the escape codes from swamping the grid
+        let mut current = None;
+        for c in frame.text.chars() {
+            let wanted = match c {
+                '\n' => None,
+                _ => self
+                    .palette
+                    .iter()
+                    .find(|&&(p, _)| p == c)
+                    .map(|&(_, col)| col),
+            };
+            if wanted != current {
+                out += wanted.map_or(RESET, Color::code);
+                current = wanted;
+            }
+            out.push(c);
+        }
+        if current.is_some() {
+            out += RESET;
+        }
+        out
+    }
+
+    /// Writes each frame to its own numbered text file in `dir`, returning how many were written.
+    pub fn dump(&self, dir: &Path) -> io::Result<usize> {
+        fs::create_dir_all(dir)?;
+        for index in 0..self.frames.len() {
+            let path = dir.join(format!("frame_{:05}.txt", index + 1));
+            fs::write(path, self.render(index, false))?;
+        }
+        Ok(self.frames.len())
+    }
+
+    /// Plays the frames in the terminal, `delay` apart. Pressing Enter pauses, and then each Enter
+    /// steps one frame, `b` steps back, a number jumps to that frame, `p` resumes and `q` quits.
+    pub fn play(&self, delay: Duration, paused: bool, color: bool) -> io::Result<()> {
+        if self.frames.is_empty() {
+            return Ok(());
+        }
+
+        // Reading stdin blocks, so a separate thread turns each line into a command. It's left
+        // behind when we return, but that only happens when the whole program is finishing.
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            for line in io::stdin().lines() {
+                let Ok(line) = line else { break };
+                if tx.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut player = Player {
+            index: 0,
+            len: self.frames.len(),
+            paused,
+        };
+        let mut out = io::stdout().lock();
+        loop {
+            write!(out, "{CLEAR}{}", self.render(player.index, color))?;
+            match player.paused {
+                true => writeln!(
+                    out,
+                    "[Enter] step  [b] back  [<n>] jump  [p] play  [q] quit"
+                )?,
+                false => writeln!(out, "[Enter] pause  [q] quit")?,
+            }
+            out.flush()?;
+
+            let command = match player.paused {
+                true => match rx.recv() {
+                    Ok(line) => Some(line),
+                    // Nothing more can come in to step with
+                    Err(_) => return Ok(()),
+                },
+                false => match rx.recv_timeout(delay) {
+                    Ok(line) => Some(line),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        thread::sleep(delay);
+                        None
+                    }
+                },
+            };
+            if !player.apply(command.as_deref()) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+// The playback position, kept apart from the terminal so the controls can be tested
+struct Player {
+    index: usize,
+    len: usize,
+    paused: bool,
+}
+
+impl Player {
+    // Handles a command, or the delay running out when there is none. Returns false once playback
+    // is over.
+    fn apply(&mut self, command: Option<&str>) -> bool {
+        let last = self.len - 1;
+        match command.map(str::trim) {
+            None if self.index == last => return false,
+            None => self.index += 1,
+            Some("q") => return false,
+            Some("") if self.paused => self.index = (self.index + 1).min(last),
+            Some("") => self.paused = true,
+            Some("p") => self.paused = false,
+            Some("b") => {
+                self.paused = true;
+                self.index = self.index.saturating_sub(1);
+            }
+            Some(n) => {
+                // Frames are numbered from 1 on screen, and anything else is just ignored
+                if let Ok(n) = n.parse::<usize>() {
+                    self.paused = true;
+                    self.index = n.clamp(1, self.len) - 1;
+                }
+            }
+        }
+        true
+    }
+}
+
+/// Days that can record their simulation as an `Animation`.
+pub trait Animate: Solution {
+    fn animate(parsed: Self::Parsed, part: u32) -> Animation;
+}
+
+/// Parses `input` and records the requested `part` (1 or 2).
+pub fn animate<S: Animate>(part: u32, input: &Input) -> Result<Animation, ParseError> {
+    assert!(part == 1 || part == 2, "Invalid part {part}");
+    Ok(S::animate(S::parse_input(input)?, part))
+}
+
+#[test]
+fn test_render() {
+    let mut animation = Animation::new(&[('#', Color::Blue), ('@', Color::Yellow)]);
+    let grid = Grid::from_lines(&["##.".to_string(), ".@#".to_string()], None, Some).unwrap();
+    animation.push_grid("start", &grid);
+    animation.push("end", ["..."]);
+
+    assert_eq!(animation.render(0, false), "Frame 1/2: start\n##.\n.@#\n");
+    assert_eq!(
+        animation.render(0, true),
+        "Frame 1/2: start\n\x1b[34m##\x1b[0m.\n.\x1b[33m@\x1b[34m#\x1b[0m\n"
+    );
+    assert_eq!(animation.render(1, true), "Frame 2/2: end\n...\n");
+}
+
+#[test]
+fn test_player() {
+    let mut player = Player {
+        index: 0,
+        len: 3,
+        paused: false,
+    };
+    assert!(player.apply(None));
+    assert_eq!((player.index, player.paused), (1, false));
+    assert!(player.apply(Some("")));
+    assert_eq!((player.index, player.paused), (1, true));
+    assert!(player.apply(Some("")));
+    assert!(player.apply(Some("")));
+    assert_eq!(player.index, 2);
+    assert!(player.apply(Some("b")));
+    assert!(player.apply(Some("x")));
+    assert_eq!(player.index, 1);
+    assert!(player.apply(Some("9")));
+    assert_eq!(player.index, 2);
+    assert!(player.apply(Some("p")));
+    assert!(!player.apply(None));
+    assert!(!player.apply(Some("q")));
+}
@@ -0,0 +1,78 @@
+// A small property checker for comparing two implementations of the same thing. Each case gets its
+// own seed so a failure can be reproduced, and a failing input is shrunk greedily until none of the
+// smaller candidates still fail, which usually leaves a counterexample small enough to read.
+
+use crate::Rng;
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+fn fails<T>(prop: &impl Fn(&T) -> bool, input: &T) -> bool {
+    // A panic counts as a failure too, since crashing on an input is a disagreement of sorts
+    !panic::catch_unwind(AssertUnwindSafe(|| prop(input))).unwrap_or(false)
+}
+
+/// Runs `prop` on `cases` generated inputs, panicking with the smallest failing input that
+/// `shrink` can reach.
+pub fn check<T: Debug>(
+    cases: u64,
+    mut gen: impl FnMut(&mut Rng) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    prop: impl Fn(&T) -> bool,
+) {
+    for seed in 0..cases {
+        let mut input = gen(&mut Rng::new(seed));
+        if !fails(&prop, &input) {
+            continue;
+        }
+
+        let mut steps = 0;
+        while let Some(smaller) = shrink(&input).into_iter().find(|c| fails(&prop, c)) {
+            input = smaller;
+            steps += 1;
+        }
+        panic!("Property failed for seed {seed}, shrunk in {steps} steps to {input:#?}");
+    }
+}
+
+/// Smaller versions of `items` with a chunk removed, trying the biggest chunks first.
+pub fn shrink_vec<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut smaller = Vec::new();
+    let mut chunk = items.len();
+    while chunk > 0 {
+        for start in (0..items.len()).step_by(chunk) {
+            let mut candidate = items[..start].to_vec();
+            candidate.extend_from_slice(&items[(start + chunk).min(items.len())..]);
+            smaller.push(candidate);
+        }
+        chunk /= 2;
+    }
+    smaller
+}
+
+/// Smaller versions of `text` with a run of chars removed.
+pub fn shrink_string(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    shrink_vec(&chars)
+        .into_iter()
+        .map(|c| c.into_iter().collect())
+        .collect()
+}
+
+#[test]
+fn test_check() {
+    assert_eq!(shrink_vec(&[1, 2, 3]).len(), 1 + 3);
+    assert_eq!(shrink_string("ab"), ["", "b", "a"]);
+    check(20, |rng| rng.below(100), |_| Vec::new(), |&n| n < 100);
+
+    // Any list containing a 7 fails, so the smallest counterexample is just [7]
+    let result = panic::catch_unwind(|| {
+        check(
+            20,
+            |rng| (0..20).map(|_| rng.below(10)).collect::<Vec<_>>(),
+            |v| shrink_vec(v),
+            |v| !v.contains(&7),
+        )
+    });
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.ends_with("to [\n    7,\n]"), "{message}");
+}
@@ -0,0 +1,91 @@
+/// An (x, y) location in a grid, with y growing downwards.
+pub type Coord = (usize, usize);
+
+/// One of the four cardinal directions. These are ordered clockwise starting from `Up`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Maps the arrow characters `^`, `>`, `v` and `<` to a direction.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// The arrow character for this direction, the reverse of `from_arrow`.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+
+    /// Moves one step from `pos`. Callers are expected to keep a border around their grid, so
+    /// stepping off of the top or left edge is a bug.
+    pub fn step(self, pos: Coord) -> Coord {
+        let (x, y) = pos;
+        match self {
+            Direction::Up => (x, y - 1),
+            Direction::Right => (x + 1, y),
+            Direction::Down => (x, y + 1),
+            Direction::Left => (x - 1, y),
+        }
+    }
+
+    /// Moves one step from `pos`, returning `None` if that would go below 0 on either axis.
+    pub fn checked_step(self, pos: Coord) -> Option<Coord> {
+        let (x, y) = pos;
+        match self {
+            Direction::Up => Some((x, y.checked_sub(1)?)),
+            Direction::Right => Some((x + 1, y)),
+            Direction::Down => Some((x, y + 1)),
+            Direction::Left => Some((x.checked_sub(1)?, y)),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Describes where a puzzle input stopped making sense. Lines and columns are 1-based so they
+/// line up with what an editor shows.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error for `token` on the line at index `line_idx`. The column is found from
+    /// where `token` sits within `line`, so pass a slice of `line` whenever possible. An empty
+    /// slice at the end of the line (`&line[line.len()..]`) works for reporting missing text.
+    pub fn new(line_idx: usize, line: &str, token: &str, message: impl Into<String>) -> Self {
+        let start = line.as_ptr() as usize;
+        let pos = token.as_ptr() as usize;
+        let column = if pos >= start && pos + token.len() <= start + line.len() {
+            line[..pos - start].chars().count() + 1
+        } else {
+            1
+        };
+        Self {
+            file: None,
+            line: line_idx + 1,
+            column,
+            text: token.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Like `new`, for parsers that work on the bytes of a line from an `Input`.
+    pub fn new_bytes(
+        line_idx: usize,
+        line: &[u8],
+        token: &[u8],
+        message: impl Into<String>,
+    ) -> Self {
+        let start = line.as_ptr() as usize;
+        let pos = token.as_ptr() as usize;
+        let column = if pos >= start && pos + token.len() <= start + line.len() {
+            String::from_utf8_lossy(&line[..pos - start])
+                .chars()
+                .count()
+                + 1
+        } else {
+            1
+        };
+        Self {
+            file: None,
+            line: line_idx + 1,
+            column,
+            text: String::from_utf8_lossy(token).into_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error for input that ended before we found everything we needed.
+    pub fn end_of_input<T>(lines: &[T], message: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            line: lines.len() + 1,
+            column: 1,
+            text: String::new(),
+            message: message.into(),
+        }
+    }
+
+    /// Parsers only see lines, so whoever read the file fills in its name afterwards.
+    pub fn in_file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(fmt, "{file}:")?;
+        }
+        write!(fmt, "{}:{}: {}", self.line, self.column, self.message)?;
+        if !self.text.is_empty() {
+            write!(fmt, ": `{}`", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token` (a slice of `line`) as a number, reporting its position if that fails.
+pub fn parse_token<T: FromStr>(line_idx: usize, line: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::new(line_idx, line, token, "Invalid number"))
+}
+
+/// Returns the `index`th token, or an error pointing at the end of `line` if there are too few.
+pub fn nth_token<'a>(
+    line_idx: usize,
+    line: &'a str,
+    toks: &[&'a str],
+    index: usize,
+) -> Result<&'a str, ParseError> {
+    toks.get(index)
+        .copied()
+        .ok_or_else(|| ParseError::new(line_idx, line, &line[line.len()..], "Missing token"))
+}
@@ -0,0 +1,279 @@
+use crate::{Coord, Direction, ParseError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// A blank space makes a handy border for char grids, since no puzzle uses it
+pub const INVALID: char = ' ';
+
+// Clockwise from up, with the diagonals in between
+const OFFSETS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A heap-backed grid sized from the input. Index with an (x, y) `Coord`, or with a row number
+/// to get that row as a slice, so `grid[y][x]` also works.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct Grid<T = char> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Creates a `width` x `height` grid of `fill`, surrounded by a 1 element `sentinel` border.
+    /// The interior starts at (1, 1), and the grid itself is 2 larger in each dimension.
+    pub fn with_border(width: usize, height: usize, fill: T, sentinel: T) -> Self {
+        let mut grid = Self::new(width + 2, height + 2, sentinel);
+        for y in 1..=height {
+            grid[y][1..=width].fill(fill.clone());
+        }
+        grid
+    }
+
+    /// Converts each character of `lines` with `convert`, which returns `None` for characters
+    /// that don't belong in the grid. Every line must be the same length. With a `border`, the
+    /// first character lands at (1, 1).
+    pub fn from_lines(
+        lines: &[String],
+        border: Option<T>,
+        mut convert: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let width = lines.first().map_or(0, |l| l.chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            if line.chars().count() != width {
+                return Err(ParseError::new(
+                    y,
+                    line,
+                    line,
+                    format!("Expected {width} columns"),
+                ));
+            }
+            for (i, c) in line.char_indices() {
+                match convert(c) {
+                    Some(v) => cells.push(v),
+                    None => {
+                        let text = &line[i..i + c.len_utf8()];
+                        return Err(ParseError::new(y, line, text, "Invalid character"));
+                    }
+                }
+            }
+        }
+        Ok(Self::finish(cells, width, lines.len(), border))
+    }
+
+    /// Like `from_lines`, but converts each byte of lines such as `Input::lines` hands out.
+    pub fn from_bytes(
+        lines: &[&[u8]],
+        border: Option<T>,
+        mut convert: impl FnMut(u8) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let width = lines.first().map_or(0, |l| l.len());
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, &line) in lines.iter().enumerate() {
+            if line.len() != width {
+                let message = format!("Expected {width} columns");
+                return Err(ParseError::new_bytes(y, line, line, message));
+            }
+            for (x, &b) in line.iter().enumerate() {
+                match convert(b) {
+                    Some(v) => cells.push(v),
+                    None => {
+                        let text = &line[x..x + 1];
+                        return Err(ParseError::new_bytes(y, line, text, "Invalid character"));
+                    }
+                }
+            }
+        }
+        Ok(Self::finish(cells, width, lines.len(), border))
+    }
+
+    fn finish(cells: Vec<T>, width: usize, height: usize, border: Option<T>) -> Self {
+        let grid = Self {
+            cells,
+            width,
+            height,
+        };
+        match border {
+            Some(sentinel) => grid.add_border(sentinel),
+            None => grid,
+        }
+    }
+
+    fn add_border(self, sentinel: T) -> Self {
+        let mut grid = Self::new(self.width + 2, self.height + 2, sentinel);
+        for (y, row) in self.rows().enumerate() {
+            grid[y + 1][1..=self.width].clone_from_slice(row);
+        }
+        grid
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, pos: Coord) -> bool {
+        pos.0 < self.width && pos.1 < self.height
+    }
+
+    pub fn get(&self, pos: Coord) -> Option<&T> {
+        if self.in_bounds(pos) {
+            Some(&self[pos])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Coord) -> Option<&mut T> {
+        if self.in_bounds(pos) {
+            Some(&mut self[pos])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() panics on 0, but an empty grid has no cells to split anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Every location in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Returns the first location of `value` scanning row by row.
+    pub fn find(&self, value: &T) -> Option<Coord>
+    where
+        T: PartialEq,
+    {
+        self.positions().find(|&pos| self[pos] == *value)
+    }
+
+    fn offset(&self, pos: Coord, (dx, dy): (isize, isize)) -> Option<Coord> {
+        let next = (pos.0.checked_add_signed(dx)?, pos.1.checked_add_signed(dy)?);
+        self.in_bounds(next).then_some(next)
+    }
+
+    /// The up/right/down/left neighbors of `pos` that are inside the grid.
+    pub fn neighbors4(&self, pos: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.offset(pos, dir.offset()))
+    }
+
+    /// Like `neighbors4`, but including diagonals.
+    pub fn neighbors8(&self, pos: Coord) -> impl Iterator<Item = Coord> + '_ {
+        OFFSETS_8
+            .into_iter()
+            .filter_map(move |offset| self.offset(pos, offset))
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Coord) -> &T {
+        assert!(
+            x < self.width,
+            "x {x} out of bounds for width {}",
+            self.width
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Coord) -> &mut T {
+        assert!(
+            x < self.width,
+            "x {x} out of bounds for width {}",
+            self.width
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = [T];
+
+    fn index(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+}
+
+impl<T: fmt::Display> fmt::Debug for Grid<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(fmt, "width: {} height: {}", self.width, self.height)?;
+        for row in self.rows() {
+            for v in row {
+                write!(fmt, "{v}")?;
+            }
+            writeln!(fmt)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_neighbors() {
+    let grid = Grid::new(3, 2, 0);
+    assert_eq!(
+        grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+        [(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbors4((1, 1)).count(), 3);
+    assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+    assert_eq!(grid.neighbors8((2, 1)).count(), 3);
+}
+
+#[test]
+fn test_border() {
+    let lines = ["ab".to_string(), "cd".to_string()];
+    let grid = Grid::from_lines(&lines, Some(INVALID), Some).unwrap();
+    assert_eq!((grid.width(), grid.height()), (4, 4));
+    assert_eq!(grid[(1, 1)], 'a');
+    assert_eq!(grid[2][2], 'd');
+    assert_eq!(grid.find(&'c'), Some((1, 2)));
+    assert_eq!(grid[0], [INVALID; 4]);
+
+    let lines = ["ab".to_string(), "c".to_string()];
+    let err = Grid::from_lines(&lines, None, Some).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 1));
+
+    let grid = Grid::from_bytes(&[b"ab", b"cd"], Some(b' '), Some).unwrap();
+    assert_eq!((grid.width(), grid.height(), grid[(2, 2)]), (4, 4, b'd'));
+    let err = Grid::from_bytes(&[b"ab", b"c!"], None, |b| {
+        b.is_ascii_lowercase().then_some(b)
+    })
+    .unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "!"));
+}
@@ -0,0 +1,108 @@
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::sync::Arc;
+
+/// Reads the lines of `path` without their trailing newlines. A `path` of `-` reads stdin, so
+/// that generated inputs can be piped straight in.
+pub fn get_input(path: &str) -> io::Result<Vec<String>> {
+    match path {
+        "-" => io::stdin().lock().lines().collect(),
+        _ => BufReader::new(File::open(path)?).lines().collect(),
+    }
+}
+
+/// A whole input, read in one go. Its lines are handed out as slices of the bytes that were read,
+/// so unlike `get_input`, nothing is allocated per line. Days with large inputs parse these.
+/// Clones share the same bytes, so a day can keep the whole input as its parsed form for free.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Input {
+    bytes: Arc<Vec<u8>>,
+}
+
+impl Input {
+    /// Reads all of `path`, or stdin for `-`.
+    pub fn read(path: &str) -> io::Result<Self> {
+        let bytes = match path {
+            "-" => {
+                let mut bytes = Vec::new();
+                io::stdin().lock().read_to_end(&mut bytes)?;
+                bytes
+            }
+            _ => fs::read(path)?,
+        };
+        Ok(Self {
+            bytes: Arc::new(bytes),
+        })
+    }
+
+    /// Joins `lines` back up into one input, for callers that already have them as strings.
+    pub fn from_lines(lines: &[String]) -> Self {
+        let mut bytes = Vec::with_capacity(lines.iter().map(|l| l.len() + 1).sum());
+        for line in lines {
+            bytes.extend_from_slice(line.as_bytes());
+            bytes.push(b'\n');
+        }
+        Self {
+            bytes: Arc::new(bytes),
+        }
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Each line without its `\n` or `\r\n`, the same lines that `get_input` reads.
+    pub fn lines(&self) -> Vec<&[u8]> {
+        let mut lines: Vec<&[u8]> = self
+            .bytes
+            .split(|&b| b == b'\n')
+            .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+            .collect();
+        // A trailing newline ends the last line rather than starting another one
+        if self.bytes.is_empty() || self.bytes.ends_with(b"\n") {
+            lines.pop();
+        }
+        lines
+    }
+
+    /// The lines as strings, for parsers that still work on text. Anything that isn't UTF-8 is
+    /// replaced, which the parser then rejects as it would any other stray character.
+    pub fn to_lines(&self) -> Vec<String> {
+        self.lines()
+            .into_iter()
+            .map(|line| String::from_utf8_lossy(line).into_owned())
+            .collect()
+    }
+}
+
+/// Reads a file checked in next to the calling crate, like `fixture!("prelim.txt")`, no matter
+/// which directory the tests are run from. Panics if it can't be read, since tests want that.
+#[macro_export]
+macro_rules! fixture {
+    ($name:expr) => {{
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/", $name);
+        $crate::get_input(path).unwrap_or_else(|e| panic!("{path}: {e}"))
+    }};
+}
+
+#[test]
+fn test_get_input() {
+    let lines = fixture!("Cargo.toml");
+    assert_eq!(lines[0], "[package]");
+    assert!(get_input("no_such_file.txt").is_err());
+}
+
+#[test]
+fn test_input_lines() {
+    let input = Input::read(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml")).unwrap();
+    assert_eq!(input.to_lines(), fixture!("Cargo.toml"));
+    assert!(Input::read("no_such_file.txt").is_err());
+
+    let input = Input {
+        bytes: Arc::new(b"ab\r\n\ncd".to_vec()),
+    };
+    assert_eq!(input.lines(), [&b"ab"[..], b"", b"cd"]);
+    assert_eq!(Input::from_lines(&input.to_lines()).bytes(), b"ab\n\ncd\n");
+    assert!(Input::default().lines().is_empty());
+    assert_eq!(input.clone().bytes().as_ptr(), input.bytes().as_ptr());
+}
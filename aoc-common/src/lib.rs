@@ -0,0 +1,26 @@
+// Shared helpers for all of the aoc2024 days. Every day used to carry its own copy of these, so
+// any fix to input handling or grid bookkeeping now only needs to be made once.
+
+mod animate;
+mod check;
+mod coord;
+mod error;
+mod grid;
+mod input;
+mod log;
+mod parallel;
+mod pathfind;
+mod rng;
+mod solution;
+
+pub use animate::{animate, Animate, Animation, Color, Frame};
+pub use check::{check, shrink_string, shrink_vec};
+pub use coord::{Coord, Direction};
+pub use error::{nth_token, parse_token, ParseError};
+pub use grid::{Grid, INVALID};
+pub use input::{get_input, Input};
+pub use log::{init_log, log_enabled, write_log, Level};
+pub use parallel::par_map;
+pub use pathfind::{astar, dijkstra, ShortestPaths};
+pub use rng::Rng;
+pub use solution::{run_day, solve, Answer, Solution};
@@ -0,0 +1,189 @@
+// Diagnostics that can be turned on without editing code. Every message has a level and a
+// target, which is the module it came from (so `aoc24`, or `aoc24::generate`). Nothing is shown
+// unless the `AOC_LOG` environment variable (or the runner's `--log` flag) asks for it, and then
+// it all goes to stderr, so answers on stdout are never mixed up with it.
+//
+// A filter is a comma separated list of levels, each either on its own to set the default, or as
+// `target=level` for a single target and everything under it. The most specific target wins:
+//
+//     AOC_LOG=aoc24=debug,aoc24::generate=off,warn
+
+use std::cmp::Reverse;
+use std::env;
+use std::fmt;
+use std::sync::OnceLock;
+
+/// How much detail a message is, from least to most.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    fn name(self) -> &'static str {
+        match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        }
+    }
+}
+
+// The most detailed level to show, where None shows nothing at all
+type Threshold = Option<Level>;
+
+fn parse_threshold(text: &str) -> Result<Threshold, String> {
+    match text.to_ascii_lowercase().as_str() {
+        "off" => Ok(None),
+        "error" => Ok(Some(Level::Error)),
+        "warn" => Ok(Some(Level::Warn)),
+        "info" => Ok(Some(Level::Info)),
+        "debug" => Ok(Some(Level::Debug)),
+        "trace" => Ok(Some(Level::Trace)),
+        _ => Err(format!("Unknown log level: {text}")),
+    }
+}
+
+#[derive(Debug, Default)]
+struct Filter {
+    default: Threshold,
+    targets: Vec<(String, Threshold)>,
+    // The most detailed level any target shows, so most messages are skipped without a lookup
+    max: Threshold,
+}
+
+impl Filter {
+    fn parse(spec: &str) -> Result<Self, String> {
+        let mut filter = Filter::default();
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            match entry.split_once('=') {
+                Some((target, level)) => {
+                    let threshold = parse_threshold(level.trim())?;
+                    filter.targets.push((target.trim().to_string(), threshold));
+                }
+                None => filter.default = parse_threshold(entry)?,
+            }
+        }
+        // Longest first, so the first match is the most specific one
+        filter
+            .targets
+            .sort_by_key(|(target, _)| Reverse(target.len()));
+        filter.max = filter
+            .targets
+            .iter()
+            .map(|&(_, threshold)| threshold)
+            .fold(filter.default, Threshold::max);
+        Ok(filter)
+    }
+
+    fn enabled(&self, level: Level, target: &str) -> bool {
+        if Some(level) > self.max {
+            return false;
+        }
+        let threshold = self
+            .targets
+            .iter()
+            .find(|(t, _)| {
+                target
+                    .strip_prefix(t.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .map_or(self.default, |&(_, threshold)| threshold);
+        Some(level) <= threshold
+    }
+}
+
+static FILTER: OnceLock<Filter> = OnceLock::new();
+
+fn filter() -> &'static Filter {
+    FILTER.get_or_init(|| {
+        let spec = env::var("AOC_LOG").unwrap_or_default();
+        Filter::parse(&spec).unwrap_or_else(|e| {
+            eprintln!("AOC_LOG: {e}");
+            Filter::default()
+        })
+    })
+}
+
+/// Sets the log filter, in place of `AOC_LOG`. It has to happen before anything is logged.
+pub fn init_log(spec: &str) -> Result<(), String> {
+    FILTER
+        .set(Filter::parse(spec)?)
+        .map_err(|_| "Logging is already set up".to_string())
+}
+
+/// Whether a message at `level` from `target` would be shown.
+pub fn log_enabled(level: Level, target: &str) -> bool {
+    filter().enabled(level, target)
+}
+
+/// Writes a message out. The macros check `log_enabled` first, so their arguments are only
+/// formatted when the message is going to be shown.
+pub fn write_log(level: Level, target: &str, args: fmt::Arguments) {
+    eprintln!("[{} {target}] {args}", level.name());
+}
+
+/// Logs a message at `level`, with the calling module as its target.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log_enabled($level, module_path!()) {
+            $crate::write_log($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+/// Logs a message at `Level::Error`.
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::Level::Error, $($arg)+) };
+}
+
+/// Logs a message at `Level::Warn`.
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::Level::Warn, $($arg)+) };
+}
+
+/// Logs a message at `Level::Info`.
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::Level::Info, $($arg)+) };
+}
+
+/// Logs a message at `Level::Debug`.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::Level::Debug, $($arg)+) };
+}
+
+/// Logs a message at `Level::Trace`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::Level::Trace, $($arg)+) };
+}
+
+#[test]
+fn test_filter() {
+    let filter = Filter::parse("aoc24=debug, aoc24::generate=off,warn").unwrap();
+    assert!(filter.enabled(Level::Debug, "aoc24"));
+    assert!(!filter.enabled(Level::Trace, "aoc24"));
+    assert!(!filter.enabled(Level::Error, "aoc24::generate"));
+    assert!(filter.enabled(Level::Warn, "aoc14"));
+    assert!(!filter.enabled(Level::Info, "aoc14"));
+    // Targets only match whole module names
+    assert!(!filter.enabled(Level::Debug, "aoc245"));
+
+    let off = Filter::parse("").unwrap();
+    assert!(!off.enabled(Level::Error, "aoc01"));
+    assert_eq!(
+        Filter::parse("aoc01=loud").unwrap_err(),
+        "Unknown log level: loud"
+    );
+}
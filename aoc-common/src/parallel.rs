@@ -0,0 +1,54 @@
+// Spreads independent work across threads for the days where that pays off. It's behind the
+// `parallel` feature so that the default build stays single threaded and easy to profile, and
+// either way the results come back in the same order as `items`, so answers never depend on
+// how the work was split up.
+
+#[cfg(feature = "parallel")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "parallel")]
+use std::{panic, thread};
+
+/// Maps `f` over `items`, on every available core when the `parallel` feature is enabled.
+#[cfg(feature = "parallel")]
+pub fn par_map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    // Each thread takes the next item as it finishes the last one, since some items can take
+    // far longer than others
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<(usize, R)> = thread::scope(|s| {
+        let handles: Vec<_> = (0..threads.min(items.len()))
+            .map(|_| {
+                s.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(i) else {
+                            return done;
+                        };
+                        done.push((i, f(item)));
+                    }
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    });
+    results.sort_unstable_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+/// Maps `f` over `items`, on every available core when the `parallel` feature is enabled.
+#[cfg(not(feature = "parallel"))]
+pub fn par_map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    items.iter().map(f).collect()
+}
+
+#[test]
+fn test_par_map() {
+    let items: Vec<u64> = (0..1000).collect();
+    let squares = par_map(&items, |x| x * x);
+    assert_eq!(squares, items.iter().map(|x| x * x).collect::<Vec<_>>());
+    assert!(par_map(&[] as &[u64], |x| *x).is_empty());
+}
@@ -0,0 +1,199 @@
+// Weighted shortest path searches over any state type. Callers describe their puzzle with a
+// `neighbors` closure that returns each reachable state along with the cost of getting there.
+//
+// Based on https://doc.rust-lang.org/nightly/std/collections/binary_heap/index.html
+// and https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+/// Everything Dijkstra found from a single start: the cheapest cost to each reachable state, and
+/// every predecessor that reaches a state at that cost. The predecessors form a DAG containing
+/// all of the shortest paths, not just one of them.
+#[derive(Clone, Debug)]
+pub struct ShortestPaths<S> {
+    pub start: S,
+    pub dist: HashMap<S, usize>,
+    pub prev: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> ShortestPaths<S> {
+    pub fn cost(&self, state: &S) -> Option<usize> {
+        self.dist.get(state).copied()
+    }
+
+    /// One cheapest path from the start to `goal` (inclusive), if `goal` was reached.
+    pub fn path_to(&self, goal: &S) -> Option<Vec<S>> {
+        self.dist.get(goal)?;
+        let mut path = vec![goal.clone()];
+        while let Some(preds) = self.prev.get(path.last().unwrap()) {
+            path.push(preds[0].clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every cheapest path from the start to `goal`. There can be exponentially many of these, so
+    /// prefer `on_paths_to` when only the visited states matter.
+    pub fn all_paths_to(&self, goal: &S) -> Vec<Vec<S>> {
+        if !self.dist.contains_key(goal) {
+            return Vec::new();
+        }
+        let mut paths = Vec::new();
+        let mut work_vec = vec![vec![goal.clone()]];
+        while let Some(path) = work_vec.pop() {
+            match self.prev.get(path.last().unwrap()) {
+                Some(preds) => {
+                    for pred in preds {
+                        let mut new_path = path.clone();
+                        new_path.push(pred.clone());
+                        work_vec.push(new_path);
+                    }
+                }
+                None => {
+                    paths.push(path.into_iter().rev().collect());
+                }
+            }
+        }
+        paths
+    }
+
+    /// Every state that lies on some cheapest path to any of `goals`.
+    pub fn on_paths_to<'a>(&self, goals: impl IntoIterator<Item = &'a S>) -> HashSet<S>
+    where
+        S: 'a,
+    {
+        let mut seen = HashSet::new();
+        let mut work_vec = goals
+            .into_iter()
+            .filter(|g| self.dist.contains_key(g))
+            .cloned()
+            .collect::<Vec<_>>();
+        while let Some(state) = work_vec.pop() {
+            if let Some(preds) = self.prev.get(&state) {
+                work_vec.extend(preds.iter().filter(|p| !seen.contains(*p)).cloned());
+            }
+            seen.insert(state);
+        }
+        seen
+    }
+}
+
+/// Runs Dijkstra's algorithm over every state reachable from `start`.
+pub fn dijkstra<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I) -> ShortestPaths<S>
+where
+    S: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut dist = HashMap::new();
+    let mut prev: HashMap<S, Vec<S>> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    dist.insert(start.clone(), 0);
+    heap.push(Reverse((0, start.clone())));
+
+    // Examine the frontier with lower cost nodes first (min-heap)
+    while let Some(Reverse((cost, state))) = heap.pop() {
+        // Important as we may have already found a better way
+        if cost > dist[&state] {
+            continue;
+        }
+
+        for (next, step_cost) in neighbors(&state) {
+            let alt = cost + step_cost;
+            match dist.get(&next) {
+                Some(&d) if alt > d => {}
+                Some(&d) if alt == d => {
+                    // Another way to get here that is just as good
+                    prev.entry(next).or_default().push(state.clone());
+                }
+                _ => {
+                    // Relaxation, we have now found a better way
+                    dist.insert(next.clone(), alt);
+                    prev.insert(next.clone(), vec![state.clone()]);
+                    heap.push(Reverse((alt, next)));
+                }
+            }
+        }
+    }
+
+    ShortestPaths { start, dist, prev }
+}
+
+/// Finds one cheapest path from `start` to any state where `is_goal` holds, returning its cost
+/// and the path (inclusive of both ends). `heuristic` must never overestimate the remaining cost,
+/// and a heuristic of 0 degrades to plain Dijkstra.
+pub fn astar<S, I>(
+    start: S,
+    mut is_goal: impl FnMut(&S) -> bool,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+) -> Option<(usize, Vec<S>)>
+where
+    S: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut dist = HashMap::new();
+    let mut prev: HashMap<S, S> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    dist.insert(start.clone(), 0);
+    heap.push(Reverse((heuristic(&start), 0, start)));
+
+    while let Some(Reverse((_, cost, state))) = heap.pop() {
+        if cost > dist[&state] {
+            continue;
+        }
+
+        if is_goal(&state) {
+            let mut path = vec![state];
+            while let Some(p) = prev.get(path.last().unwrap()) {
+                path.push(p.clone());
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+
+        for (next, step_cost) in neighbors(&state) {
+            let alt = cost + step_cost;
+            if dist.get(&next).is_none_or(|&d| alt < d) {
+                dist.insert(next.clone(), alt);
+                prev.insert(next.clone(), state.clone());
+                heap.push(Reverse((alt + heuristic(&next), alt, next)));
+            }
+        }
+    }
+
+    None
+}
+
+// A small diamond where both routes from 0 to 3 cost 2
+#[cfg(test)]
+fn diamond(n: &u32) -> Vec<(u32, usize)> {
+    match n {
+        0 => vec![(1, 1), (2, 1)],
+        1 | 2 => vec![(3, 1)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn test_dijkstra() {
+    let paths = dijkstra(0, diamond);
+    assert_eq!(paths.cost(&3), Some(2));
+    assert_eq!(paths.path_to(&3).unwrap().len(), 3);
+    let mut all = paths.all_paths_to(&3);
+    all.sort();
+    assert_eq!(all, [[0, 1, 3], [0, 2, 3]]);
+    assert_eq!(paths.on_paths_to(&[3]).len(), 4);
+    assert_eq!(paths.cost(&4), None);
+}
+
+#[test]
+fn test_astar() {
+    let (cost, path) = astar(0, |&n| n == 3, diamond, |&n| 3 - n as usize).unwrap();
+    assert_eq!(cost, 2);
+    assert_eq!(path.len(), 3);
+    assert_eq!(astar(0, |&n| n == 4, diamond, |_| 0), None);
+}
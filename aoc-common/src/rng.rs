@@ -0,0 +1,75 @@
+// A tiny seeded random number generator for building puzzle inputs. Being able to reproduce an
+// input from its seed matters far more here than statistical quality, so SplitMix64 is plenty.
+//
+// Based on https://prng.di.unimi.it/splitmix64.c
+
+use std::ops::Range;
+
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `0..n`, which must not be empty.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Empty range");
+        // Multiplying instead of taking the remainder avoids most of the modulo bias
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// A value in `range`, which must not be empty.
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(range.start < range.end, "Empty range");
+        let span = range.end.abs_diff(range.start);
+        range
+            .start
+            .wrapping_add(((self.next_u64() as u128 * span as u128) >> 64) as i64)
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        // The top 53 bits fill an f64 mantissa exactly
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Fisher-Yates shuffle.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[test]
+fn test_rng() {
+    let mut rng = Rng::new(2024);
+    let first: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+    let mut again = Rng::new(2024);
+    assert!(first.iter().all(|&v| v == again.next_u64()));
+
+    for _ in 0..1000 {
+        assert!(rng.below(7) < 7);
+        assert!((-3..4).contains(&rng.range(-3..4)));
+    }
+    let mut items: Vec<u32> = (0..20).collect();
+    rng.shuffle(&mut items);
+    items.sort();
+    assert_eq!(items, (0..20).collect::<Vec<_>>());
+}
@@ -0,0 +1,147 @@
+use crate::{Input, ParseError};
+use std::env;
+use std::fmt;
+use std::path::Path;
+
+/// The result of solving one part of a puzzle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    // Some parts (like day 25 part 2) have nothing to compute, and others were solved by hand
+    Unsolved,
+    // A part that couldn't get to an answer for this input, like when a total overflows
+    Error(String),
+}
+
+impl Answer {
+    /// Splits off a part that failed, for callers that report failures apart from answers.
+    pub fn into_result(self) -> Result<Answer, String> {
+        match self {
+            Answer::Error(e) => Err(e),
+            answer => Ok(answer),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(fmt, "{n}"),
+            Answer::Text(s) => write!(fmt, "{s}"),
+            Answer::Unsolved => write!(fmt, "unsolved"),
+            Answer::Error(e) => write!(fmt, "error: {e}"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(v: $t) -> Self {
+                    Answer::Number(v as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(u32, u64, usize, i64, isize, i128);
+
+// Only the largest answers from the `checked` features don't fit in a Number, and they still
+// print the same as text
+impl From<u128> for Answer {
+    fn from(v: u128) -> Self {
+        i128::try_from(v).map_or_else(|_| Answer::Text(v.to_string()), Answer::Number)
+    }
+}
+
+// Parts that can fail give back a Result, with the message for when they do
+impl<T: Into<Answer>> From<Result<T, String>> for Answer {
+    fn from(result: Result<T, String>) -> Self {
+        result.map_or_else(Answer::Error, T::into)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// Common interface for every day, so that one runner can drive all of them.
+///
+/// Each part gets its own freshly parsed input, which lets parts mutate it freely.
+pub trait Solution {
+    /// The puzzle day, 1 through 25.
+    const DAY: u32;
+
+    type Parsed;
+
+    /// Malformed input is reported as a `ParseError` rather than a panic.
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError>;
+
+    /// Parses the input as it was read. The runners always come through here, so days with large
+    /// inputs can work on the bytes directly instead of a `String` per line.
+    fn parse_input(input: &Input) -> Result<Self::Parsed, ParseError> {
+        Self::parse(&input.to_lines())
+    }
+
+    fn part1(parsed: Self::Parsed) -> Answer;
+
+    fn part2(_parsed: Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+/// Parses `input` and solves the requested `part` (1 or 2).
+pub fn solve<S: Solution>(part: u32, input: &Input) -> Result<Answer, ParseError> {
+    let parsed = S::parse_input(input)?;
+    Ok(match part {
+        1 => S::part1(parsed),
+        2 => S::part2(parsed),
+        _ => panic!("Invalid part {part}"),
+    })
+}
+
+fn print_answer<S: Solution>(part: u32, name: &str, input: &Input) {
+    match solve::<S>(part, input).map(Answer::into_result) {
+        Ok(Err(e)) => eprintln!("Day {} part {part} ({name}): {e}", S::DAY),
+        Ok(Ok(answer)) => println!("Day {} part {part} ({name}): {answer}", S::DAY),
+        Err(e) => eprintln!("Day {} part {part}: {}", S::DAY, e.in_file(name)),
+    }
+}
+
+/// Solves part 1 for each of `part1_files` and then part 2 for each of `part2_files`, printing
+/// every answer. This is what each day's binary runs, with `dir` being the day's
+/// `CARGO_MANIFEST_DIR` so it works from any directory. A path on the command line (or `-` for
+/// stdin) is run through both parts instead.
+pub fn run_day<S: Solution>(dir: &str, part1_files: &[&str], part2_files: &[&str]) {
+    if let Some(path) = env::args().nth(1) {
+        match Input::read(&path) {
+            Ok(input) => {
+                print_answer::<S>(1, &path, &input);
+                print_answer::<S>(2, &path, &input);
+            }
+            Err(e) => eprintln!("{path}: {e}"),
+        }
+        return;
+    }
+
+    for (part, filenames) in [(1, part1_files), (2, part2_files)] {
+        for filename in filenames {
+            let path = Path::new(dir).join(filename);
+            match Input::read(&path.to_string_lossy()) {
+                Ok(input) => print_answer::<S>(part, filename, &input),
+                Err(e) => eprintln!("{}: {e}", path.display()),
+            }
+        }
+    }
+}
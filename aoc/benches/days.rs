@@ -0,0 +1,17 @@
+// Runs the same benchmarks as `aoc bench`, so that `cargo bench -p aoc` works without any extra
+// dependencies. Pass a day number to only benchmark that day.
+
+use aoc::bench::{bench_day, format_table};
+use aoc::DAYS;
+use std::env;
+use std::time::Duration;
+
+fn main() {
+    // cargo passes --bench along with any filter, so just look for a day number
+    let day = env::args().skip(1).find_map(|a| a.parse::<u32>().ok());
+    let mut rows = Vec::new();
+    for d in DAYS.iter().filter(|d| day.is_none_or(|day| d.day == day)) {
+        rows.extend(bench_day(d, Duration::from_millis(200)));
+    }
+    print!("{}", format_table(&rows, None));
+}
@@ -0,0 +1,241 @@
+// The expected answers manifest, `answers.toml` at the top of the tree. It's a small subset of
+// TOML: a list of `[[answer]]` tables, each with integer `day` and `part` keys, an `input` path
+// relative to the manifest, and an `expected` answer that is either an integer or a string.
+
+use crate::find_day;
+use aoc_common::{par_map, Answer, Input, ParseError};
+use std::panic;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expected {
+    pub day: u32,
+    pub part: u32,
+    pub input: PathBuf,
+    pub expected: Answer,
+}
+
+/// An entry whose answer didn't match, or that couldn't be solved at all.
+#[derive(Debug)]
+pub struct Mismatch {
+    pub entry: Expected,
+    pub actual: Result<Answer, String>,
+}
+
+/// The manifest checked in at the top of the tree.
+pub fn default_manifest() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("answers.toml")
+}
+
+#[derive(Default)]
+struct Partial {
+    line_idx: usize,
+    day: Option<u32>,
+    part: Option<u32>,
+    input: Option<String>,
+    expected: Option<Answer>,
+}
+
+impl Partial {
+    fn finish(self, lines: &[&str], dir: &Path) -> Result<Expected, ParseError> {
+        let line = lines[self.line_idx];
+        let missing = |key| ParseError::new(self.line_idx, line, line, format!("Missing {key}"));
+        Ok(Expected {
+            day: self.day.ok_or_else(|| missing("day"))?,
+            part: self.part.ok_or_else(|| missing("part"))?,
+            input: dir.join(self.input.ok_or_else(|| missing("input"))?),
+            expected: self.expected.ok_or_else(|| missing("expected"))?,
+        })
+    }
+}
+
+// Splits off a trailing comment, as long as the '#' isn't inside a string
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn parse_value(line_idx: usize, line: &str, value: &str) -> Result<Answer, ParseError> {
+    let Some(quoted) = value.strip_prefix('"') else {
+        return value
+            .parse()
+            .map(Answer::Number)
+            .map_err(|_| ParseError::new(line_idx, line, value, "Expected an integer or string"));
+    };
+
+    let mut text = String::new();
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if chars.as_str().is_empty() => return Ok(Answer::Text(text)),
+            '\\' => match chars.next() {
+                Some(c @ ('"' | '\\')) => text.push(c),
+                _ => return Err(ParseError::new(line_idx, line, value, "Unsupported escape")),
+            },
+            _ => text.push(c),
+        }
+    }
+    Err(ParseError::new(
+        line_idx,
+        line,
+        value,
+        "Unterminated string",
+    ))
+}
+
+/// Parses the manifest `text`. Relative input paths are resolved against `dir`.
+pub fn parse_manifest(text: &str, dir: &Path) -> Result<Vec<Expected>, ParseError> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut entries = Vec::new();
+    let mut current: Option<Partial> = None;
+
+    for (i, &line) in lines.iter().enumerate() {
+        let trimmed = strip_comment(line).trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if trimmed == "[[answer]]" {
+            if let Some(entry) = current.take() {
+                entries.push(entry.finish(&lines, dir)?);
+            }
+            current = Some(Partial {
+                line_idx: i,
+                ..Default::default()
+            });
+            continue;
+        }
+
+        let Some((key, value)) = trimmed.split_once('=') else {
+            return Err(ParseError::new(i, line, trimmed, "Expected key = value"));
+        };
+        let (key, value) = (key.trim(), value.trim());
+        let Some(entry) = current.as_mut() else {
+            return Err(ParseError::new(i, line, key, "Key outside of [[answer]]"));
+        };
+        let number = |what| match parse_value(i, line, value)? {
+            Answer::Number(n) => u32::try_from(n)
+                .map_err(|_| ParseError::new(i, line, value, format!("Invalid {what}"))),
+            _ => Err(ParseError::new(i, line, value, format!("Invalid {what}"))),
+        };
+        match key {
+            "day" => entry.day = Some(number("day")?),
+            "part" => match number("part")? {
+                part @ (1 | 2) => entry.part = Some(part),
+                _ => return Err(ParseError::new(i, line, value, "Part must be 1 or 2")),
+            },
+            "input" => match parse_value(i, line, value)? {
+                Answer::Text(path) => entry.input = Some(path),
+                _ => return Err(ParseError::new(i, line, value, "Input must be a string")),
+            },
+            "expected" => entry.expected = Some(parse_value(i, line, value)?),
+            _ => return Err(ParseError::new(i, line, key, "Unknown key")),
+        }
+    }
+
+    if let Some(entry) = current {
+        entries.push(entry.finish(&lines, dir)?);
+    }
+    Ok(entries)
+}
+
+/// Reads and parses the manifest at `path`.
+pub fn read_manifest(path: &Path) -> Result<Vec<Expected>, String> {
+    let name = path.to_string_lossy();
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{name}: {e}"))?;
+    let dir = path.parent().unwrap_or(Path::new("."));
+    parse_manifest(&text, dir).map_err(|e| e.in_file(&name).to_string())
+}
+
+/// Solves one part of `day` on the input at `path`. A solver that panics fails like any other,
+/// so that one bad input doesn't stop the rest.
+pub fn solve_part(day: u32, part: u32, path: &Path) -> Result<Answer, String> {
+    panic::catch_unwind(|| {
+        let name = path.to_string_lossy();
+        let day = find_day(day).ok_or(format!("Day {day} is not solved"))?;
+        let input = Input::read(&name).map_err(|e| format!("{name}: {e}"))?;
+        (day.solve)(part, &input)
+            .map_err(|e| e.in_file(&name).to_string())
+            .and_then(Answer::into_result)
+    })
+    .unwrap_or_else(|_| Err("Solver panicked".to_string()))
+}
+
+/// Solves a single manifest entry.
+pub fn solve_entry(entry: &Expected) -> Result<Answer, String> {
+    solve_part(entry.day, entry.part, &entry.input)
+}
+
+/// Solves every entry (across every core with the `parallel` feature, since some parts take a
+/// while) and returns the ones that don't match. Answers are compared as text, so
+/// `expected = "42"` and `expected = 42` agree.
+pub fn check_all(entries: &[Expected]) -> Vec<Mismatch> {
+    let actual = par_map(entries, solve_entry);
+    entries
+        .iter()
+        .zip(actual)
+        .filter_map(|(entry, actual)| match &actual {
+            Ok(answer) if answer.to_string() == entry.expected.to_string() => None,
+            _ => Some(Mismatch {
+                entry: entry.clone(),
+                actual,
+            }),
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_manifest() {
+    let text = r#"
+# A comment
+[[answer]]
+day = 18
+part = 2
+input = "aoc18/prelim.txt" # "6,1" is text
+expected = "6,1"
+
+[[answer]]
+day = 1
+part = 1
+input = "aoc01/prelim.txt"
+expected = 11
+"#;
+    let entries = parse_manifest(text, Path::new("top")).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].input, Path::new("top/aoc18/prelim.txt"));
+    assert_eq!(entries[0].expected, Answer::Text("6,1".to_string()));
+    assert_eq!(entries[1].expected, Answer::Number(11));
+
+    let err = parse_manifest("[[answer]]\nday = 1\npart = 3\n", Path::new(".")).unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (3, 8, "3"));
+    let err = parse_manifest("[[answer]]\nday = 1\n", Path::new(".")).unwrap_err();
+    assert_eq!((err.line, err.message.as_str()), (1, "Missing part"));
+}
+
+#[test]
+fn test_answers() {
+    let entries = read_manifest(&default_manifest()).unwrap();
+    let mismatches = check_all(&entries);
+    for m in &mismatches {
+        let input = m.entry.input.to_string_lossy();
+        match &m.actual {
+            Ok(actual) => eprintln!(
+                "Day {} part {} ({input}): expected {}, got {actual}",
+                m.entry.day, m.entry.part, m.entry.expected
+            ),
+            Err(e) => eprintln!("Day {} part {}: {e}", m.entry.day, m.entry.part),
+        }
+    }
+    assert!(mismatches.is_empty());
+}
@@ -0,0 +1,186 @@
+// Other people's puzzle inputs, laid out as `inputs/<user>/dayNN.txt`. Next to each input, an
+// optional `dayNN.answers` holds the expected answer for part 1 on its first line and part 2 on
+// its second. A missing or blank line leaves that part unchecked, and unchecked parts are still
+// run, so that errors in them are reported.
+
+use crate::answers::{solve_part, Expected};
+use aoc_common::{par_map, Answer};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The per-user inputs at the top of the tree.
+pub fn default_inputs_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("inputs")
+}
+
+/// Everything found for one user.
+#[derive(Debug, Default)]
+pub struct UserInputs {
+    pub user: String,
+    pub entries: Vec<Expected>,
+    /// Parts that have no expected answer, so can only be run.
+    pub unchecked: Vec<Unchecked>,
+}
+
+/// A part of an input with nothing to check its answer against.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unchecked {
+    pub day: u32,
+    pub part: u32,
+    pub input: PathBuf,
+}
+
+// Answers are stored as text, so `42` and `"42"` don't need telling apart
+fn parse_answer(line: &str) -> Option<Answer> {
+    match line.trim() {
+        "" => None,
+        s => Some(s.parse().map(Answer::Number).unwrap_or(s.into())),
+    }
+}
+
+/// Parses an answers file for `day`, with one line per part.
+pub fn parse_answers(text: &str, day: u32, input: &Path) -> Vec<Expected> {
+    text.lines()
+        .take(2)
+        .zip(1..)
+        .filter_map(|(line, part)| {
+            Some(Expected {
+                day,
+                part,
+                input: input.to_path_buf(),
+                expected: parse_answer(line)?,
+            })
+        })
+        .collect()
+}
+
+// Sorted, so that a batch always runs in the same order
+fn sorted_entries(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let name = dir.to_string_lossy();
+    let mut paths = fs::read_dir(dir)
+        .and_then(|entries| {
+            entries
+                .map(|e| e.map(|e| e.path()))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .map_err(|e| format!("{name}: {e}"))?;
+    paths.sort();
+    Ok(paths)
+}
+
+/// Finds every user's inputs under `dir`, optionally only for `user`.
+pub fn scan_inputs(dir: &Path, user: Option<&str>) -> Result<Vec<UserInputs>, String> {
+    let mut users = Vec::new();
+    for user_dir in sorted_entries(dir)? {
+        let Some(name) = user_dir
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+        else {
+            continue;
+        };
+        if !user_dir.is_dir() || user.is_some_and(|u| u != name) {
+            continue;
+        }
+
+        let mut inputs = UserInputs {
+            user: name,
+            ..Default::default()
+        };
+        for path in sorted_entries(&user_dir)? {
+            let Some(file) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            let Some(day) = file
+                .strip_prefix("day")
+                .and_then(|f| f.strip_suffix(".txt"))
+                .and_then(|d| d.parse::<u32>().ok())
+            else {
+                continue;
+            };
+
+            let answers = path.with_extension("answers");
+            let entries = match fs::read_to_string(&answers) {
+                Ok(text) => parse_answers(&text, day, &path),
+                Err(_) if !answers.exists() => Vec::new(),
+                Err(e) => return Err(format!("{}: {e}", answers.to_string_lossy())),
+            };
+            for part in [1, 2] {
+                if !entries.iter().any(|e| e.part == part) {
+                    inputs.unchecked.push(Unchecked {
+                        day,
+                        part,
+                        input: path.clone(),
+                    });
+                }
+            }
+            inputs.entries.extend(entries);
+        }
+        users.push(inputs);
+    }
+
+    if let Some(user) = user {
+        if users.is_empty() {
+            return Err(format!("No inputs for {user} in {}", dir.to_string_lossy()));
+        }
+    }
+    Ok(users)
+}
+
+/// Runs every unchecked part, and returns the ones that fail along with their errors.
+pub fn run_unchecked(parts: &[Unchecked]) -> Vec<(Unchecked, String)> {
+    let results = par_map(parts, |p| solve_part(p.day, p.part, &p.input));
+    parts
+        .iter()
+        .zip(results)
+        .filter_map(|(part, result)| Some((part.clone(), result.err()?)))
+        .collect()
+}
+
+#[test]
+fn test_parse_answers() {
+    let input = Path::new("inputs/someone/day23.txt");
+    let entries = parse_answers("7\nco,de,ka,ta\n", 23, input);
+    assert_eq!(entries.len(), 2);
+    assert_eq!(
+        (entries[0].part, &entries[0].expected),
+        (1, &Answer::Number(7))
+    );
+    assert_eq!(entries[1].expected, Answer::Text("co,de,ka,ta".to_string()));
+
+    // Only part 2 is known
+    let entries = parse_answers("\n31\n", 1, input);
+    assert_eq!((entries.len(), entries[0].part), (1, 2));
+}
+
+#[test]
+fn test_batch() {
+    let users = scan_inputs(&default_inputs_dir(), None).unwrap();
+    assert!(users.iter().any(|u| !u.entries.is_empty()));
+    for user in &users {
+        let mismatches = crate::answers::check_all(&user.entries);
+        assert!(mismatches.is_empty(), "{}: {mismatches:?}", user.user);
+        let failures = run_unchecked(&user.unchecked);
+        assert!(failures.is_empty(), "{}: {failures:?}", user.user);
+    }
+}
+
+#[test]
+fn test_run_unchecked() {
+    let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+    let user = dir.join("someone");
+    fs::create_dir_all(&user).unwrap();
+    // Day 1 has only part 1's answer, and day 2 has no answers file and doesn't parse
+    fs::write(user.join("day01.txt"), "3   4\n4   3\n").unwrap();
+    fs::write(user.join("day01.answers"), "2\n").unwrap();
+    fs::write(user.join("day02.txt"), "1 2 x\n").unwrap();
+
+    let users = scan_inputs(&dir, Some("someone")).unwrap();
+    let parts: Vec<_> = users[0].unchecked.iter().map(|u| (u.day, u.part)).collect();
+    assert_eq!(parts, [(1, 2), (2, 1), (2, 2)]);
+    let failures = run_unchecked(&users[0].unchecked);
+    assert_eq!(failures.len(), 2);
+    assert!(failures[0].1.contains("Invalid number"));
+    fs::remove_dir_all(&dir).unwrap();
+}
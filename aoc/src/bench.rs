@@ -0,0 +1,266 @@
+// A small offline benchmark harness in the spirit of criterion: each stage gets a warmup run and
+// is then sampled until a time budget runs out, and we report the spread of those samples.
+
+use crate::{day_file, Day};
+use aoc_common::{Answer, Input, ParseError, Solution};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// Enough samples for a stable median without letting tiny stages run forever
+const MAX_SAMPLES: usize = 1000;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+/// Timings for one input file. A part is `None` when it isn't run on that file or is unsolved.
+#[derive(Clone, Debug)]
+pub struct Timings {
+    pub parse: Stats,
+    pub parts: [Option<Stats>; 2],
+}
+
+/// Benchmarks one solution on some lines, for the parts that are enabled.
+pub type BenchFn = fn(&Input, [bool; 2], Duration) -> Result<Timings, ParseError>;
+
+pub struct Row {
+    pub day: u32,
+    pub file: String,
+    pub timings: Result<Timings, String>,
+}
+
+/// Calls `sample` (which returns how long its timed section took) once to warm up, and then
+/// repeatedly until `budget` is spent. A stage slower than the whole budget just keeps its
+/// warmup time as the only sample, rather than running again.
+pub fn measure(budget: Duration, mut sample: impl FnMut() -> Duration) -> Stats {
+    let start = Instant::now();
+    let warmup = sample();
+    let mut times = Vec::new();
+    while start.elapsed() < budget && times.len() < MAX_SAMPLES {
+        times.push(sample());
+    }
+    if times.is_empty() {
+        times.push(warmup);
+    }
+
+    times.sort();
+    let total: Duration = times.iter().sum();
+    Stats {
+        samples: times.len(),
+        min: times[0],
+        median: times[times.len() / 2],
+        mean: total / times.len() as u32,
+        max: times[times.len() - 1],
+    }
+}
+
+/// Benchmarks parsing `input`, and each part enabled in `parts`. Parts consume their input, so
+/// every sample parses a fresh copy outside of the timed section.
+pub fn bench_solution<S: Solution>(
+    input: &Input,
+    parts: [bool; 2],
+    budget: Duration,
+) -> Result<Timings, ParseError> {
+    // Bail out early on bad input, so that the closures below can unwrap
+    S::parse_input(input)?;
+    let parse = measure(budget, || {
+        let now = Instant::now();
+        black_box(S::parse_input(black_box(input)).unwrap());
+        now.elapsed()
+    });
+
+    let mut timings = Timings {
+        parse,
+        parts: [None, None],
+    };
+    for (idx, part) in [S::part1, S::part2].into_iter().enumerate() {
+        if !parts[idx] {
+            continue;
+        }
+        let mut unsolved = false;
+        let stats = measure(budget, || {
+            let parsed = S::parse_input(input).unwrap();
+            let now = Instant::now();
+            let answer = black_box(part(black_box(parsed)));
+            let elapsed = now.elapsed();
+            unsolved = answer == Answer::Unsolved;
+            elapsed
+        });
+        timings.parts[idx] = (!unsolved).then_some(stats);
+    }
+    Ok(timings)
+}
+
+/// Benchmarks every input file that `day` is run against.
+pub fn bench_day(day: &Day, budget: Duration) -> Vec<Row> {
+    let mut files: Vec<&str> = Vec::new();
+    for file in day.files.iter().flat_map(|f| f.iter()) {
+        if !files.contains(file) {
+            files.push(file);
+        }
+    }
+    // Keep the real input last, after all of the examples. It isn't always checked in, in which
+    // case there's nothing to time.
+    files.sort_by_key(|&f| f == "input.txt");
+    files.retain(|&f| f != "input.txt" || day_file(day.day, f).exists());
+
+    files
+        .into_iter()
+        .map(|file| {
+            let path = day_file(day.day, file);
+            let parts = [day.files[0].contains(&file), day.files[1].contains(&file)];
+            let name = path.to_string_lossy();
+            let timings = match Input::read(&name) {
+                Ok(input) => {
+                    (day.bench)(&input, parts, budget).map_err(|e| e.in_file(&name).to_string())
+                }
+                Err(e) => Err(format!("{name}: {e}")),
+            };
+            Row {
+                day: day.day,
+                file: file.to_string(),
+                timings,
+            }
+        })
+        .collect()
+}
+
+/// Formats a duration with 3 significant digits and a sensible unit.
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos() as f64;
+    let (value, unit) = if nanos < 1e3 {
+        (nanos, "ns")
+    } else if nanos < 1e6 {
+        (nanos / 1e3, "µs")
+    } else if nanos < 1e9 {
+        (nanos / 1e6, "ms")
+    } else {
+        (nanos / 1e9, "s")
+    };
+    let precision = if value < 10.0 {
+        2
+    } else if value < 100.0 {
+        1
+    } else {
+        0
+    };
+    format!("{value:.precision$}{unit}")
+}
+
+// The median of each stage in nanoseconds, keyed by (day, file)
+type Baseline = HashMap<(u32, String), [Option<u128>; 3]>;
+
+fn medians(timings: &Timings) -> [Option<u128>; 3] {
+    let median = |s: &Option<Stats>| s.map(|s| s.median.as_nanos());
+    [
+        Some(timings.parse.median.as_nanos()),
+        median(&timings.parts[0]),
+        median(&timings.parts[1]),
+    ]
+}
+
+/// Saves the median of every stage as tab separated values, for comparing against later.
+pub fn baseline_tsv(rows: &[Row]) -> String {
+    let mut out = String::from("day\tfile\tparse_ns\tpart1_ns\tpart2_ns\n");
+    for row in rows {
+        let Ok(timings) = &row.timings else {
+            continue;
+        };
+        let cells = medians(timings)
+            .map(|m| m.map_or("-".to_string(), |ns| ns.to_string()))
+            .join("\t");
+        writeln!(out, "{}\t{}\t{cells}", row.day, row.file).unwrap();
+    }
+    out
+}
+
+/// Reads back the output of `baseline_tsv`. Lines that don't parse are skipped.
+pub fn parse_baseline(text: &str) -> Baseline {
+    let mut baseline = Baseline::new();
+    for line in text.lines().skip(1) {
+        let toks: Vec<&str> = line.split('\t').collect();
+        let [day, file, stages @ ..] = toks.as_slice() else {
+            continue;
+        };
+        let (Ok(day), [parse, part1, part2]) = (day.parse(), stages) else {
+            continue;
+        };
+        let stages = [parse, part1, part2].map(|s| s.parse().ok());
+        baseline.insert((day, file.to_string()), stages);
+    }
+    baseline
+}
+
+/// Renders the median of each stage as a table, with the change from `baseline` if given.
+pub fn format_table(rows: &[Row], baseline: Option<&Baseline>) -> String {
+    let mut out = format!(
+        "{:>3}  {:<18} {:>18} {:>18} {:>18}\n",
+        "day", "file", "parse", "part 1", "part 2"
+    );
+    for row in rows {
+        let timings = match &row.timings {
+            Ok(timings) => timings,
+            Err(e) => {
+                writeln!(out, "{:>3}  {:<18} error: {e}", row.day, row.file).unwrap();
+                continue;
+            }
+        };
+        let old = baseline.and_then(|b| b.get(&(row.day, row.file.clone())));
+        let cells = medians(timings).into_iter().enumerate().map(|(i, m)| {
+            let Some(ns) = m else {
+                return "-".to_string();
+            };
+            let cell = format_duration(Duration::from_nanos(ns as u64));
+            match old.and_then(|o| o[i]) {
+                Some(old_ns) if old_ns > 0 => {
+                    let change = (ns as f64 - old_ns as f64) * 100.0 / old_ns as f64;
+                    format!("{cell} ({change:+.1}%)")
+                }
+                _ => cell,
+            }
+        });
+        write!(out, "{:>3}  {:<18}", row.day, row.file).unwrap();
+        for cell in cells {
+            write!(out, " {cell:>18}").unwrap();
+        }
+        writeln!(out).unwrap();
+    }
+    out
+}
+
+#[test]
+fn test_format_duration() {
+    assert_eq!(format_duration(Duration::from_nanos(512)), "512ns");
+    assert_eq!(format_duration(Duration::from_nanos(1_234)), "1.23µs");
+    assert_eq!(format_duration(Duration::from_micros(45_600)), "45.6ms");
+    assert_eq!(format_duration(Duration::from_secs(2)), "2.00s");
+}
+
+#[test]
+fn test_baseline_round_trip() {
+    let stats = measure(Duration::ZERO, || Duration::from_nanos(1500));
+    assert_eq!(stats.samples, 1);
+    assert_eq!(stats.median, Duration::from_nanos(1500));
+
+    let rows = [Row {
+        day: 3,
+        file: "prelim.txt".to_string(),
+        timings: Ok(Timings {
+            parse: stats,
+            parts: [Some(stats), None],
+        }),
+    }];
+    let baseline = parse_baseline(&baseline_tsv(&rows));
+    assert_eq!(
+        baseline[&(3, "prelim.txt".to_string())],
+        [Some(1500), Some(1500), None]
+    );
+    assert!(format_table(&rows, Some(&baseline)).contains("1.50µs (+0.0%)"));
+}
@@ -0,0 +1,213 @@
+// Registry of every solved day, so that a single binary can run any of them.
+
+pub mod answers;
+pub mod batch;
+pub mod bench;
+pub mod report;
+pub mod scaffold;
+
+use aoc_common::{animate, solve, Animation, Answer, Input, ParseError, Rng, Solution};
+use bench::{bench_solution, BenchFn};
+use std::path::PathBuf;
+
+pub struct Day {
+    pub day: u32,
+    pub solve: fn(u32, &Input) -> Result<Answer, ParseError>,
+    /// The files in the day's directory that each part is run against, same as its own binary.
+    pub files: [&'static [&'static str]; 2],
+    pub bench: BenchFn,
+    /// Builds a random but valid input of roughly `size`, as described in the day's generator.
+    pub generate: fn(&mut Rng, usize) -> Vec<String>,
+    /// Records a part as a sequence of frames, for the days that simulate a grid.
+    pub animate: Option<AnimateFn>,
+}
+
+pub type AnimateFn = fn(u32, &Input) -> Result<Animation, ParseError>;
+
+macro_rules! day {
+    ($krate:ident :: $solution:ident, $part1_files:expr, $part2_files:expr) => {
+        Day {
+            day: <$krate::$solution as Solution>::DAY,
+            solve: solve::<$krate::$solution>,
+            files: [&$part1_files, &$part2_files],
+            bench: bench_solution::<$krate::$solution>,
+            generate: $krate::generate::generate,
+            animate: None,
+        }
+    };
+    ($krate:ident :: $solution:ident, $part1_files:expr, $part2_files:expr, animate) => {
+        Day {
+            animate: Some(animate::<$krate::$solution>),
+            ..day!($krate::$solution, $part1_files, $part2_files)
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(
+        aoc01::Day01,
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "input.txt"]
+    ),
+    day!(
+        aoc02::Day02,
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "input.txt"]
+    ),
+    day!(
+        aoc03::Day03,
+        ["prelim.txt", "input.txt"],
+        ["prelim2.txt", "input.txt"]
+    ),
+    day!(
+        aoc04::Day04,
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "input.txt"]
+    ),
+    day!(
+        aoc05::Day05,
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "input.txt"]
+    ),
+    day!(
+        aoc06::Day06,
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "input.txt"],
+        animate
+    ),
+    day!(
+        aoc07::Day07,
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "input.txt"]
+    ),
+    day!(
+        aoc08::Day08,
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "input.txt"]
+    ),
+    day!(
+        aoc09::Day09,
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "input.txt"]
+    ),
+    day!(
+        aoc10::Day10,
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "input.txt"]
+    ),
+    day!(aoc11::Day11, ["prelim.txt", "input.txt"], ["input.txt"]),
+    day!(
+        aoc12::Day12,
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "prelim_holes.txt", "input.txt"]
+    ),
+    day!(
+        aoc13::Day13,
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "input.txt"]
+    ),
+    day!(aoc14::Day14, ["prelim.txt", "input.txt"], [], animate),
+    day!(
+        aoc15::Day15,
+        ["prelim.txt", "prelim2.txt", "input.txt"],
+        ["prelim3.txt", "prelim2.txt", "input.txt"],
+        animate
+    ),
+    day!(
+        aoc16::Day16,
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "input.txt"],
+        animate
+    ),
+    day!(
+        aoc17::Day17,
+        ["prelim.txt", "input.txt"],
+        ["prelim2.txt", "input.txt"]
+    ),
+    day!(
+        aoc18::Day18,
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "input.txt"],
+        animate
+    ),
+    day!(
+        aoc19::Day19,
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "input.txt"]
+    ),
+    day!(
+        aoc20::Day20,
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "input.txt"]
+    ),
+    day!(aoc21::Day21, ["prelim.txt", "input.txt"], ["input.txt"]),
+    day!(
+        aoc22::Day22,
+        ["prelim.txt", "input.txt"],
+        ["prelim2.txt", "input.txt"]
+    ),
+    day!(
+        aoc23::Day23,
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "input.txt"]
+    ),
+    day!(
+        aoc24::Day24,
+        ["prelim.txt", "prelim2.txt", "input.txt"],
+        ["input.txt"]
+    ),
+    day!(aoc25::Day25, ["prelim.txt", "input.txt"], []),
+];
+
+pub fn find_day(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+/// A file checked in next to a day's crate, like `prelim.txt`.
+pub fn day_file(day: u32, name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("aoc{day:02}"))
+        .join(name)
+}
+
+/// The puzzle input checked in next to each day's crate.
+pub fn default_input(day: u32) -> PathBuf {
+    day_file(day, "input.txt")
+}
+
+#[test]
+fn test_generate() {
+    for day in DAYS {
+        let lines = (day.generate)(&mut Rng::new(u64::from(day.day)), 12);
+        assert_eq!(lines, (day.generate)(&mut Rng::new(u64::from(day.day)), 12));
+
+        // Every part has to be solvable, not just parse
+        for seed in 0..3 {
+            let lines = (day.generate)(&mut Rng::new(seed), 12);
+            let input = Input::from_lines(&lines);
+            for part in [1, 2] {
+                let result = (day.solve)(part, &input).map_err(|e| e.to_string());
+                if let Err(e) = result.and_then(Answer::into_result) {
+                    panic!("Day {} seed {seed}: {e}\n{}", day.day, lines.join("\n"));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_animate() {
+    for day in DAYS {
+        let Some(animate) = day.animate else { continue };
+        let input = Input::from_lines(&(day.generate)(&mut Rng::new(0), 12));
+        for part in [1, 2] {
+            let animation = animate(part, &input).unwrap();
+            assert!(
+                !animation.frames().is_empty(),
+                "Day {} part {part}",
+                day.day
+            );
+        }
+    }
+}
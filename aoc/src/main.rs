@@ -0,0 +1,511 @@
+use aoc::answers::check_all;
+use aoc::batch::{default_inputs_dir, run_unchecked, scan_inputs};
+use aoc::bench::{baseline_tsv, bench_day, format_table, parse_baseline};
+use aoc::report::Report;
+use aoc::scaffold::{default_root, scaffold};
+use aoc::{day_file, default_input, find_day, DAYS};
+use aoc_common::{init_log, Answer, Input, Rng};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "Usage: aoc run --day <day|all> [--part <1|2>] [--input <path|->] [--json]
+       aoc batch [--dir <path>] [--user <name>]
+       aoc bench [--day <day>] [--time <secs>] [--save <path>] [--compare <path>]
+       aoc gen --day <day> [--size <size>] [--seed <seed>] [--output <path>]
+       aoc animate --day <day> [--part <1|2>] [--input <path>] [--delay <ms>] [--step]
+                   [--dump <dir>] [--no-color]
+       aoc new --day <day>
+
+A leading --log <filter> turns on diagnostics, the same as AOC_LOG=<filter>.";
+
+struct RunArgs {
+    days: Vec<u32>,
+    parts: Vec<u32>,
+    input: Option<String>,
+    json: bool,
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut days = None;
+    let mut parts = vec![1, 2];
+    let mut input = None;
+    let mut json = false;
+
+    let mut arg_iter = args.iter();
+    while let Some(arg) = arg_iter.next() {
+        let mut value = || {
+            arg_iter
+                .next()
+                .ok_or_else(|| format!("Missing value for {arg}"))
+        };
+        match arg.as_str() {
+            "--day" => {
+                let v = value()?;
+                days = Some(match v.as_str() {
+                    "all" => DAYS.iter().map(|d| d.day).collect(),
+                    _ => vec![v.parse::<u32>().map_err(|_| format!("Invalid day: {v}"))?],
+                });
+            }
+            "--part" => {
+                let v = value()?;
+                match v.as_str() {
+                    "1" => parts = vec![1],
+                    "2" => parts = vec![2],
+                    _ => return Err(format!("Invalid part: {v}")),
+                }
+            }
+            "--input" => {
+                input = Some(value()?.clone());
+            }
+            "--json" => {
+                json = true;
+            }
+            _ => return Err(format!("Unknown argument: {arg}")),
+        }
+    }
+
+    let days = days.ok_or("Missing --day")?;
+    if days.len() > 1 && input.is_some() {
+        return Err("--input needs a single --day".to_string());
+    }
+    Ok(RunArgs {
+        days,
+        parts,
+        input,
+        json,
+    })
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let args = parse_run_args(args)?;
+    // Keep going after a failure, so that one bad day or part doesn't hide the others
+    let mut failed = false;
+    for &d in &args.days {
+        let day = find_day(d).ok_or(format!("Day {d} is not solved"))?;
+        // Not every day's input is checked in, and that shouldn't fail all of the others
+        if args.input.is_none() && args.days.len() > 1 && !default_input(d).exists() {
+            eprintln!("Day {d}: no input.txt, skipping");
+            continue;
+        }
+        let input = match &args.input {
+            Some(input) => input.clone(),
+            None => default_input(day.day).to_string_lossy().into_owned(),
+        };
+
+        // A missing input fails each part, so that it's reported the same way as a bad one
+        let contents = Input::read(&input).map_err(|e| format!("{input}: {e}"));
+        for &part in &args.parts {
+            let now = Instant::now();
+            let result = match &contents {
+                Ok(contents) => (day.solve)(part, contents)
+                    .map_err(|e| e.in_file(&input).to_string())
+                    .and_then(Answer::into_result),
+                Err(e) => Err(e.clone()),
+            };
+            let elapsed = now.elapsed();
+            if args.json {
+                let report = Report {
+                    day: day.day,
+                    part,
+                    input: &input,
+                    result: result.clone(),
+                    elapsed,
+                };
+                println!("{}", report.to_json());
+            }
+            match result {
+                Ok(answer) if !args.json => println!("Day {} part {part}: {answer}", day.day),
+                Ok(_) => {}
+                Err(e) => {
+                    eprintln!("Day {} part {part}: {e}", day.day);
+                    failed = true;
+                }
+            }
+        }
+    }
+    match (failed, args.days.len()) {
+        (false, _) => Ok(()),
+        (true, 1) => Err("Some parts failed".to_string()),
+        (true, _) => Err("Some days failed".to_string()),
+    }
+}
+
+struct BatchArgs {
+    dir: PathBuf,
+    user: Option<String>,
+}
+
+fn parse_batch_args(args: &[String]) -> Result<BatchArgs, String> {
+    let mut batch_args = BatchArgs {
+        dir: default_inputs_dir(),
+        user: None,
+    };
+
+    let mut arg_iter = args.iter();
+    while let Some(arg) = arg_iter.next() {
+        let mut value = || {
+            arg_iter
+                .next()
+                .ok_or_else(|| format!("Missing value for {arg}"))
+        };
+        match arg.as_str() {
+            "--dir" => {
+                batch_args.dir = PathBuf::from(value()?);
+            }
+            "--user" => {
+                batch_args.user = Some(value()?.clone());
+            }
+            _ => return Err(format!("Unknown argument: {arg}")),
+        }
+    }
+    Ok(batch_args)
+}
+
+fn batch(args: &[String]) -> Result<(), String> {
+    let args = parse_batch_args(args)?;
+    let users = scan_inputs(&args.dir, args.user.as_deref())?;
+
+    let mut total_mismatches = 0;
+    let mut total_failures = 0;
+    for user in &users {
+        let mismatches = check_all(&user.entries);
+        for m in &mismatches {
+            let (day, part) = (m.entry.day, m.entry.part);
+            match &m.actual {
+                Ok(actual) => println!(
+                    "{} day {day} part {part}: expected {}, got {actual}",
+                    user.user, m.entry.expected
+                ),
+                Err(e) => println!("{} day {day} part {part}: {e}", user.user),
+            }
+        }
+        // Parts with no answer to check can still fail to parse or solve
+        let failures = run_unchecked(&user.unchecked);
+        for (u, e) in &failures {
+            println!("{} day {} part {}: {e}", user.user, u.day, u.part);
+        }
+        println!(
+            "{}: {} of {} answers match",
+            user.user,
+            user.entries.len() - mismatches.len(),
+            user.entries.len()
+        );
+        if !user.unchecked.is_empty() {
+            println!(
+                "{}: {} of {} parts without answers ran cleanly",
+                user.user,
+                user.unchecked.len() - failures.len(),
+                user.unchecked.len()
+            );
+        }
+        total_mismatches += mismatches.len();
+        total_failures += failures.len();
+    }
+
+    match (total_mismatches, total_failures) {
+        (0, 0) => Ok(()),
+        (n, 0) => Err(format!("{n} answers didn't match")),
+        (0, f) => Err(format!("{f} parts without answers failed")),
+        (n, f) => Err(format!(
+            "{n} answers didn't match and {f} parts without answers failed"
+        )),
+    }
+}
+
+struct BenchArgs {
+    day: Option<u32>,
+    budget: Duration,
+    save: Option<String>,
+    compare: Option<String>,
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut bench_args = BenchArgs {
+        day: None,
+        // Per stage, which keeps a full run over every day to a minute or two
+        budget: Duration::from_millis(200),
+        save: None,
+        compare: None,
+    };
+
+    let mut arg_iter = args.iter();
+    while let Some(arg) = arg_iter.next() {
+        let mut value = || {
+            arg_iter
+                .next()
+                .ok_or_else(|| format!("Missing value for {arg}"))
+        };
+        match arg.as_str() {
+            "--day" => {
+                let v = value()?;
+                bench_args.day = Some(v.parse::<u32>().map_err(|_| format!("Invalid day: {v}"))?);
+            }
+            "--time" => {
+                let v = value()?;
+                let secs = v
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(|s| Duration::try_from_secs_f64(s).ok())
+                    .ok_or_else(|| format!("Invalid time: {v}"))?;
+                bench_args.budget = secs;
+            }
+            "--save" => {
+                bench_args.save = Some(value()?.clone());
+            }
+            "--compare" => {
+                bench_args.compare = Some(value()?.clone());
+            }
+            _ => return Err(format!("Unknown argument: {arg}")),
+        }
+    }
+    Ok(bench_args)
+}
+
+fn bench(args: &[String]) -> Result<(), String> {
+    let args = parse_bench_args(args)?;
+    let days = match args.day {
+        Some(d) => vec![find_day(d).ok_or(format!("Day {d} is not solved"))?],
+        None => DAYS.iter().collect(),
+    };
+    let baseline = match &args.compare {
+        Some(path) => {
+            let text = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+            Some(parse_baseline(&text))
+        }
+        None => None,
+    };
+
+    let mut rows = Vec::new();
+    for day in days {
+        eprintln!("Benchmarking day {}", day.day);
+        rows.extend(bench_day(day, args.budget));
+    }
+    print!("{}", format_table(&rows, baseline.as_ref()));
+
+    if let Some(path) = &args.save {
+        fs::write(path, baseline_tsv(&rows)).map_err(|e| format!("{path}: {e}"))?;
+    }
+    Ok(())
+}
+
+struct GenArgs {
+    day: u32,
+    size: usize,
+    seed: u64,
+    output: Option<String>,
+}
+
+fn parse_gen_args(args: &[String]) -> Result<GenArgs, String> {
+    let mut day = None;
+    // Big enough to be interesting, without making the slower days crawl
+    let mut size = 100;
+    let mut seed = 0;
+    let mut output = None;
+
+    let mut arg_iter = args.iter();
+    while let Some(arg) = arg_iter.next() {
+        let mut value = || {
+            arg_iter
+                .next()
+                .ok_or_else(|| format!("Missing value for {arg}"))
+        };
+        match arg.as_str() {
+            "--day" => {
+                let v = value()?;
+                day = Some(v.parse::<u32>().map_err(|_| format!("Invalid day: {v}"))?);
+            }
+            "--size" => {
+                let v = value()?;
+                size = v.parse().map_err(|_| format!("Invalid size: {v}"))?;
+            }
+            "--seed" => {
+                let v = value()?;
+                seed = v.parse().map_err(|_| format!("Invalid seed: {v}"))?;
+            }
+            "--output" => {
+                output = Some(value()?.clone());
+            }
+            _ => return Err(format!("Unknown argument: {arg}")),
+        }
+    }
+
+    let day = day.ok_or("Missing --day")?;
+    Ok(GenArgs {
+        day,
+        size,
+        seed,
+        output,
+    })
+}
+
+fn generate(args: &[String]) -> Result<(), String> {
+    let args = parse_gen_args(args)?;
+    let day = find_day(args.day).ok_or(format!("Day {} is not solved", args.day))?;
+    let mut text = (day.generate)(&mut Rng::new(args.seed), args.size).join("\n");
+    text.push('\n');
+    match &args.output {
+        Some(path) => fs::write(path, text).map_err(|e| format!("{path}: {e}")),
+        None => {
+            print!("{text}");
+            Ok(())
+        }
+    }
+}
+
+struct AnimateArgs {
+    day: u32,
+    part: u32,
+    input: Option<String>,
+    delay: Duration,
+    step: bool,
+    dump: Option<String>,
+    color: bool,
+}
+
+fn parse_animate_args(args: &[String]) -> Result<AnimateArgs, String> {
+    let mut day = None;
+    let mut animate_args = AnimateArgs {
+        day: 0,
+        part: 1,
+        input: None,
+        delay: Duration::from_millis(100),
+        step: false,
+        dump: None,
+        color: true,
+    };
+
+    let mut arg_iter = args.iter();
+    while let Some(arg) = arg_iter.next() {
+        let mut value = || {
+            arg_iter
+                .next()
+                .ok_or_else(|| format!("Missing value for {arg}"))
+        };
+        match arg.as_str() {
+            "--day" => {
+                let v = value()?;
+                day = Some(v.parse::<u32>().map_err(|_| format!("Invalid day: {v}"))?);
+            }
+            "--part" => {
+                let v = value()?;
+                animate_args.part = match v.as_str() {
+                    "1" => 1,
+                    "2" => 2,
+                    _ => return Err(format!("Invalid part: {v}")),
+                };
+            }
+            "--input" => {
+                animate_args.input = Some(value()?.clone());
+            }
+            "--delay" => {
+                let v = value()?;
+                let ms = v.parse().map_err(|_| format!("Invalid delay: {v}"))?;
+                animate_args.delay = Duration::from_millis(ms);
+            }
+            "--step" => {
+                animate_args.step = true;
+            }
+            "--dump" => {
+                animate_args.dump = Some(value()?.clone());
+            }
+            "--no-color" => {
+                animate_args.color = false;
+            }
+            _ => return Err(format!("Unknown argument: {arg}")),
+        }
+    }
+
+    animate_args.day = day.ok_or("Missing --day")?;
+    Ok(animate_args)
+}
+
+fn animate(args: &[String]) -> Result<(), String> {
+    let args = parse_animate_args(args)?;
+    let day = find_day(args.day).ok_or(format!("Day {} is not solved", args.day))?;
+    let record = day
+        .animate
+        .ok_or(format!("Day {} has no animation", args.day))?;
+    // The examples are small enough to watch, so they make a better default than input.txt
+    let input = match &args.input {
+        Some(input) => input.clone(),
+        None => match day.files[args.part as usize - 1].first() {
+            Some(name) => day_file(day.day, name),
+            None => default_input(day.day),
+        }
+        .to_string_lossy()
+        .into_owned(),
+    };
+
+    let contents = Input::read(&input).map_err(|e| format!("{input}: {e}"))?;
+    let animation = record(args.part, &contents).map_err(|e| e.in_file(&input).to_string())?;
+    match &args.dump {
+        Some(dir) => {
+            let frames = animation
+                .dump(Path::new(dir))
+                .map_err(|e| format!("{dir}: {e}"))?;
+            eprintln!("Wrote {frames} frames to {dir}");
+            Ok(())
+        }
+        None => animation
+            .play(args.delay, args.step, args.color)
+            .map_err(|e| e.to_string()),
+    }
+}
+
+fn new_day(args: &[String]) -> Result<(), String> {
+    let day = match args {
+        [flag, v] if flag == "--day" => {
+            v.parse::<u32>().map_err(|_| format!("Invalid day: {v}"))?
+        }
+        _ => return Err("Missing --day".to_string()),
+    };
+    let root = default_root();
+    for path in scaffold(&root, day)? {
+        println!(
+            "Wrote {}",
+            path.strip_prefix(&root).unwrap_or(&path).display()
+        );
+    }
+    println!(
+        "Save the example as aoc{day:02}/prelim.txt and the puzzle input as aoc{day:02}/input.txt, \
+         then fill in the answers stubbed out in answers.toml."
+    );
+    Ok(())
+}
+
+// Takes a leading `--log <filter>` off of the arguments, since it applies to every subcommand
+fn init_logging(args: &[String]) -> Result<&[String], String> {
+    match args {
+        [flag, filter, rest @ ..] if flag == "--log" => {
+            init_log(filter)?;
+            Ok(rest)
+        }
+        [flag] if flag == "--log" => Err("Missing value for --log".to_string()),
+        _ => Ok(args),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = init_logging(&args).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(2);
+    });
+    let result = match args.first().map(|a| a.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("batch") => batch(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("gen") => generate(&args[1..]),
+        Some("animate") => animate(&args[1..]),
+        Some("new") => new_day(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{e}");
+        process::exit(2);
+    }
+}
@@ -0,0 +1,71 @@
+// Machine readable results, so scripts don't have to scrape the human readable output. Each
+// report is a single line JSON object, which makes a whole run valid JSON Lines.
+
+use aoc_common::Answer;
+use std::fmt::Write as _;
+use std::time::Duration;
+
+pub struct Report<'a> {
+    pub day: u32,
+    pub part: u32,
+    pub input: &'a str,
+    pub result: Result<Answer, String>,
+    pub elapsed: Duration,
+}
+
+/// Quotes and escapes `s` as a JSON string.
+pub fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < ' ' => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+impl Report<'_> {
+    /// Numeric answers are JSON numbers, text answers are strings, and unsolved parts are null.
+    /// A failed part has an "error" instead of an "answer".
+    pub fn to_json(&self) -> String {
+        let result = match &self.result {
+            Ok(Answer::Number(n)) => format!("\"answer\":{n}"),
+            Ok(Answer::Text(s)) => format!("\"answer\":{}", json_string(s)),
+            Ok(Answer::Unsolved) => "\"answer\":null".to_string(),
+            Ok(Answer::Error(e)) | Err(e) => format!("\"error\":{}", json_string(e)),
+        };
+        format!(
+            "{{\"day\":{},\"part\":{},\"input\":{},{result},\"elapsed_ns\":{}}}",
+            self.day,
+            self.part,
+            json_string(self.input),
+            self.elapsed.as_nanos()
+        )
+    }
+}
+
+#[test]
+fn test_to_json() {
+    let mut report = Report {
+        day: 23,
+        part: 2,
+        input: "C:\\aoc\\\"input\".txt",
+        result: Ok(Answer::Text("co,de,ka,ta".to_string())),
+        elapsed: Duration::from_micros(5),
+    };
+    assert_eq!(
+        report.to_json(),
+        r#"{"day":23,"part":2,"input":"C:\\aoc\\\"input\".txt","answer":"co,de,ka,ta","elapsed_ns":5000}"#
+    );
+
+    report.result = Err("bad\tinput\u{1}".to_string());
+    assert!(report.to_json().contains(r#""error":"bad\tinput\u0001""#));
+}
@@ -0,0 +1,402 @@
+// Sets up the crate for a new day, laid out like every other one: a library with the solver, a
+// generator and tests for the example and the real input, a binary that runs the examples and
+// `input.txt`, and entries in the workspace, the runner and `answers.toml`. Every change is worked
+// out before anything is written, nothing that already exists is ever overwritten, and if a write
+// fails then everything written so far is undone.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = r#"[package]
+name = "aoc{dd}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+aoc-common = { path = "../aoc-common" }
+"#;
+
+const MAIN_RS: &str = r#"use aoc{dd}::Day{dd};
+use aoc_common::run_day;
+
+fn main() {
+    run_day::<Day{dd}>(
+        env!("CARGO_MANIFEST_DIR"),
+        &["prelim.txt", "input.txt"],
+        &["prelim.txt", "input.txt"],
+    );
+}
+"#;
+
+const LIB_RS: &str = r#"//! Day {day}.
+
+pub mod generate;
+
+#[cfg(test)]
+use aoc_common::fixture;
+use aoc_common::{Answer, ParseError, Solution};
+
+/// Day {day}.
+pub struct Day{dd};
+
+impl Solution for Day{dd} {
+    const DAY: u32 = {day};
+
+    type Parsed = Vec<String>;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        Ok(lines.to_vec())
+    }
+
+    fn part1(_parsed: Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(_parsed: Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[test]
+fn test_prelim() {
+    assert!(Day{dd}::parse(&fixture!("prelim.txt")).is_ok());
+}
+
+// Ignored until input.txt is downloaded
+#[test]
+#[ignore]
+fn test_input() {
+    assert!(Day{dd}::parse(&fixture!("input.txt")).is_ok());
+}
+"#;
+
+const GENERATE_RS: &str = r#"// A random input of roughly `size`, which so far is nothing at all.
+
+use aoc_common::Rng;
+
+pub fn generate(_rng: &mut Rng, _size: usize) -> Vec<String> {
+    Vec::new()
+}
+"#;
+
+const DAYS_ENTRY: &str = r#"    day!(
+        aoc{dd}::Day{dd},
+        ["prelim.txt", "input.txt"],
+        ["prelim.txt", "input.txt"]
+    ),"#;
+
+const ANSWER_STUB: &str = r#"# [[answer]]
+# day = {day}
+# part = {part}
+# input = "aoc{dd}/{file}"
+# expected = 0
+"#;
+
+/// The top of the tree, where every day's crate lives.
+pub fn default_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn fill(template: &str, day: u32) -> String {
+    template
+        .replace("{dd}", &format!("{day:02}"))
+        .replace("{day}", &day.to_string())
+}
+
+// The two digit day at the start of `text`, as in `aoc07 = ...`
+fn day_number(text: &str) -> Option<u32> {
+    text.strip_prefix("aoc")?.get(..2)?.parse().ok()
+}
+
+// Finds where a new day goes in a list of per-day entries, so they stay in order. `start` gives
+// the day of the entry that begins at the first of the lines it's given, and the list ends on the
+// first line after an entry that `end` matches.
+fn find_slot(
+    lines: &[&str],
+    name: &str,
+    day: u32,
+    start: impl Fn(&[&str]) -> Option<u32>,
+    end: impl Fn(&str) -> bool,
+) -> Result<usize, String> {
+    let first = (0..lines.len())
+        .find(|&i| start(&lines[i..]).is_some())
+        .ok_or_else(|| format!("{name}: Can't find where the days are listed"))?;
+    let mut at = lines.len();
+    for i in first..lines.len() {
+        match start(&lines[i..]) {
+            Some(d) if d == day => return Err(format!("{name} already has day {day}")),
+            Some(d) if d > day => {
+                at = i;
+                break;
+            }
+            None if end(lines[i]) => {
+                at = i;
+                break;
+            }
+            _ => {}
+        }
+    }
+    Ok(at)
+}
+
+fn splice(lines: &[&str], at: usize, entry: &str) -> String {
+    let mut result: Vec<&str> = lines[..at].to_vec();
+    result.extend(entry.split('\n'));
+    result.extend(&lines[at..]);
+    result.join("\n") + "\n"
+}
+
+// A new version of a file that lists the days, along with what it was before
+struct Edit {
+    path: PathBuf,
+    text: String,
+    original: String,
+}
+
+// Works out the new contents of every file that lists the days
+fn registry_edits(root: &Path, day: u32) -> Result<Vec<Edit>, String> {
+    let read = |name: &str| {
+        let path = root.join(name);
+        fs::read_to_string(&path)
+            .map(|text| (path, text))
+            .map_err(|e| format!("{name}: {e}"))
+    };
+    let mut edits = Vec::new();
+
+    let (path, original) = read("Cargo.toml")?;
+    let lines: Vec<&str> = original.lines().collect();
+    let at = find_slot(
+        &lines,
+        "Cargo.toml",
+        day,
+        |lines| day_number(lines[0].trim().strip_prefix('"')?),
+        |line| line.trim() == "]",
+    )?;
+    let text = splice(&lines, at, &fill("    \"aoc{dd}\",", day));
+    edits.push(Edit {
+        path,
+        text,
+        original,
+    });
+
+    let (path, original) = read("aoc/Cargo.toml")?;
+    let lines: Vec<&str> = original.lines().collect();
+    let at = find_slot(
+        &lines,
+        "aoc/Cargo.toml",
+        day,
+        |lines| day_number(lines[0]).filter(|_| lines[0].contains("path")),
+        |line| line.trim().is_empty() || line.starts_with('['),
+    )?;
+    let text = splice(
+        &lines,
+        at,
+        &fill("aoc{dd} = { path = \"../aoc{dd}\" }", day),
+    );
+    edits.push(Edit {
+        path,
+        text,
+        original,
+    });
+
+    let (path, original) = read("aoc/src/lib.rs")?;
+    let lines: Vec<&str> = original.lines().collect();
+    let at = find_slot(
+        &lines,
+        "aoc/src/lib.rs",
+        day,
+        |lines| match lines[0].strip_prefix("    day!(")? {
+            "" => day_number(lines.get(1)?.trim()),
+            rest => day_number(rest),
+        },
+        |line| line == "];",
+    )?;
+    let text = splice(&lines, at, &fill(DAYS_ENTRY, day));
+    edits.push(Edit {
+        path,
+        text,
+        original,
+    });
+
+    // The stubs are commented out until the answers are known, but still kept in order. Answers
+    // for the day that are already there are left alone.
+    let (path, original) = read("answers.toml")?;
+    let lines: Vec<&str> = original.lines().collect();
+    let answer_day = |lines: &[&str]| {
+        let uncomment = |line: &str| line.strip_prefix("# ").unwrap_or(line).to_string();
+        match uncomment(lines[0]).as_str() {
+            "[[answer]]" => uncomment(lines.get(1)?)
+                .strip_prefix("day = ")?
+                .parse()
+                .ok(),
+            _ => None,
+        }
+    };
+    if let Ok(at) = find_slot(&lines, "answers.toml", day, answer_day, |_| false) {
+        let stubs = [1, 2]
+            .iter()
+            .flat_map(|part| {
+                ["prelim.txt", "input.txt"].map(|file| {
+                    fill(ANSWER_STUB, day)
+                        .replace("{part}", &part.to_string())
+                        .replace("{file}", file)
+                })
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        // Entries are separated by blank lines, and the file ends with the last one
+        let stubs = match at == lines.len() {
+            true => format!("\n{}", stubs.trim_end()),
+            false => stubs,
+        };
+        edits.push(Edit {
+            path,
+            text: splice(&lines, at, &stubs),
+            original,
+        });
+    }
+
+    Ok(edits)
+}
+
+fn create(path: &Path, contents: &str) -> Result<(), String> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Creates the `aocNN` crate for `day` under `root` and registers it everywhere the days are
+/// listed, returning every file that was written. Fails without changing anything if the day is
+/// already there.
+pub fn scaffold(root: &Path, day: u32) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day: {day}"));
+    }
+    let dir = root.join(format!("aoc{day:02}"));
+    if dir.exists() {
+        return Err(format!("aoc{day:02} already exists"));
+    }
+    let edits = registry_edits(root, day)?;
+
+    fs::create_dir(&dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+    let files = [
+        ("Cargo.toml", CARGO_TOML),
+        ("src/main.rs", MAIN_RS),
+        ("src/lib.rs", LIB_RS),
+        ("src/generate.rs", GENERATE_RS),
+        ("prelim.txt", ""),
+    ]
+    .map(|(name, template)| (name, fill(template, day)));
+    let mut written = Vec::new();
+    if let Err(e) = write_all(&dir, &files, &edits, &mut written) {
+        undo(&dir, &edits);
+        return Err(e);
+    }
+    Ok(written)
+}
+
+// Writes the new crate's files and then the edits, adding each one to `written` as it goes
+fn write_all(
+    dir: &Path,
+    files: &[(&str, String)],
+    edits: &[Edit],
+    written: &mut Vec<PathBuf>,
+) -> Result<(), String> {
+    let src = dir.join("src");
+    fs::create_dir(&src).map_err(|e| format!("{}: {e}", src.display()))?;
+    for (name, contents) in files {
+        let path = dir.join(name);
+        create(&path, contents)?;
+        written.push(path);
+    }
+    for edit in edits {
+        fs::write(&edit.path, &edit.text).map_err(|e| format!("{}: {e}", edit.path.display()))?;
+        written.push(edit.path.clone());
+    }
+    Ok(())
+}
+
+// Puts everything back after a write fails, so that nothing is left half done and the day can
+// just be scaffolded again. Every edited file gets its original back, since the one that failed
+// may have been partly written.
+fn undo(dir: &Path, edits: &[Edit]) {
+    let _ = fs::remove_dir_all(dir);
+    for edit in edits {
+        let _ = fs::write(&edit.path, &edit.original);
+    }
+}
+
+#[test]
+fn test_scaffold() {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("aoc/src")).unwrap();
+    fs::create_dir(root.join("aoc01")).unwrap();
+    let files = [
+        (
+            "Cargo.toml",
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"aoc01\",\n    \"aoc03\",\n]\n",
+        ),
+        (
+            "aoc/Cargo.toml",
+            "[dependencies]\naoc01 = { path = \"../aoc01\" }\naoc03 = { path = \"../aoc03\" }\n",
+        ),
+        (
+            "aoc/src/lib.rs",
+            "pub const DAYS: &[Day] = &[\n    day!(\n        aoc01::Day01,\n        [],\n        []\n    ),\n    day!(aoc03::Day03, [], []),\n];\n",
+        ),
+        ("answers.toml", "[[answer]]\nday = 3\npart = 1\n"),
+    ];
+    for (name, text) in files {
+        fs::write(root.join(name), text).unwrap();
+    }
+
+    let written = scaffold(&root, 2).unwrap();
+    assert!(written.contains(&root.join("aoc02/src/lib.rs")));
+    let read = |name: &str| fs::read_to_string(root.join(name)).unwrap();
+    assert!(read("Cargo.toml").contains("\"aoc01\",\n    \"aoc02\",\n    \"aoc03\","));
+    assert!(read("aoc/Cargo.toml").contains("../aoc02\" }\naoc03"));
+    assert!(read("aoc/src/lib.rs").contains("    ),\n    day!(\n        aoc02::Day02,"));
+    assert!(read("answers.toml").starts_with("# [[answer]]\n# day = 2\n"));
+    assert!(read("aoc02/src/main.rs").contains("run_day::<Day02>"));
+    assert!(read("aoc02/src/lib.rs").contains("#[ignore]\nfn test_input() {"));
+
+    // Nothing is touched for a day that's already there
+    let lib = read("aoc02/src/lib.rs") + "// solved";
+    fs::write(root.join("aoc02/src/lib.rs"), &lib).unwrap();
+    let cargo = read("Cargo.toml");
+    assert!(scaffold(&root, 2).is_err());
+    assert!(scaffold(&root, 1).is_err());
+    assert_eq!((read("aoc02/src/lib.rs"), read("Cargo.toml")), (lib, cargo));
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn test_scaffold_undo() {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-undo-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    let dir = root.join("aoc02");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(root.join("Cargo.toml"), "old").unwrap();
+    // The second edit can't be written, since its directory doesn't exist
+    let edit = |name: &str| Edit {
+        path: root.join(name),
+        text: "new".to_string(),
+        original: "old".to_string(),
+    };
+    let edits = [edit("Cargo.toml"), edit("missing/Cargo.toml")];
+    let files = [("Cargo.toml", String::new())];
+
+    let mut written = Vec::new();
+    assert!(write_all(&dir, &files, &edits, &mut written).is_err());
+    assert_eq!(written.len(), 2);
+    undo(&dir, &edits);
+    assert!(!dir.exists());
+    assert_eq!(fs::read_to_string(root.join("Cargo.toml")).unwrap(), "old");
+    assert!(!root.join("missing").exists());
+    fs::remove_dir_all(&root).unwrap();
+}
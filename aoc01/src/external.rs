@@ -0,0 +1,279 @@
+// Distances between lists that are too big to hold in memory. The input is read a line at a time,
+// and each column is cut into runs of at most `run_len` IDs, which are sorted and written out to
+// temporary files. The runs are then merged back together, a few at a time if there are a lot of
+// them, and the merged columns are read in step with each other while the differences are summed.
+
+use crate::{parse_row, width, Id};
+use aoc_common::ParseError;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// How many IDs from each column are kept in memory by default.
+pub const RUN_LEN: usize = 1 << 20;
+
+// The most runs that are merged at once, so that only this many files are ever open per column
+const FAN_IN: usize = 64;
+
+/// Why a list couldn't be read.
+#[derive(Debug)]
+pub enum ExternalError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for ExternalError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExternalError::Io(e) => write!(fmt, "{e}"),
+            ExternalError::Parse(e) => write!(fmt, "{e}"),
+        }
+    }
+}
+
+impl Error for ExternalError {}
+
+impl From<io::Error> for ExternalError {
+    fn from(e: io::Error) -> Self {
+        ExternalError::Io(e)
+    }
+}
+
+impl From<ParseError> for ExternalError {
+    fn from(e: ParseError) -> Self {
+        ExternalError::Parse(e)
+    }
+}
+
+// A sorted run of IDs in a temporary file, which is removed once the run is dropped
+struct Run {
+    path: PathBuf,
+    len: usize,
+}
+
+impl Run {
+    fn write(dir: &Path, ids: impl Iterator<Item = io::Result<Id>>) -> io::Result<Self> {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let name = format!(
+            "aoc01-{}-{}.run",
+            process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        );
+        let mut run = Run {
+            path: dir.join(name),
+            len: 0,
+        };
+        let mut out = BufWriter::new(File::create(&run.path)?);
+        for id in ids {
+            out.write_all(&id?.to_le_bytes())?;
+            run.len += 1;
+        }
+        out.flush()?;
+        Ok(run)
+    }
+}
+
+impl Drop for Run {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+// Reads a run back in order
+struct RunReader {
+    reader: BufReader<File>,
+    left: usize,
+    _run: Run,
+}
+
+impl RunReader {
+    fn new(run: Run) -> io::Result<Self> {
+        Ok(Self {
+            reader: BufReader::new(File::open(&run.path)?),
+            left: run.len,
+            _run: run,
+        })
+    }
+}
+
+impl Iterator for RunReader {
+    type Item = io::Result<Id>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.left == 0 {
+            return None;
+        }
+        self.left -= 1;
+        let mut bytes = [0; 8];
+        Some(
+            self.reader
+                .read_exact(&mut bytes)
+                .map(|_| Id::from_le_bytes(bytes)),
+        )
+    }
+}
+
+// The IDs from several runs, smallest first
+struct Merge {
+    readers: Vec<RunReader>,
+    heap: BinaryHeap<Reverse<(Id, usize)>>,
+}
+
+impl Merge {
+    fn new(runs: Vec<Run>) -> io::Result<Self> {
+        let mut merge = Merge {
+            readers: runs
+                .into_iter()
+                .map(RunReader::new)
+                .collect::<io::Result<_>>()?,
+            heap: BinaryHeap::new(),
+        };
+        for i in 0..merge.readers.len() {
+            merge.refill(i)?;
+        }
+        Ok(merge)
+    }
+
+    fn refill(&mut self, i: usize) -> io::Result<()> {
+        if let Some(id) = self.readers[i].next() {
+            self.heap.push(Reverse((id?, i)));
+        }
+        Ok(())
+    }
+}
+
+impl Iterator for Merge {
+    type Item = io::Result<Id>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((id, i)) = self.heap.pop()?;
+        Some(self.refill(i).map(|_| id))
+    }
+}
+
+// Collects a single column into sorted runs
+struct Column {
+    buffer: Vec<Id>,
+    runs: Vec<Run>,
+}
+
+impl Column {
+    fn flush(&mut self, dir: &Path) -> io::Result<()> {
+        if !self.buffer.is_empty() {
+            self.buffer.sort();
+            let run = Run::write(dir, self.buffer.drain(..).map(Ok))?;
+            self.runs.push(run);
+        }
+        Ok(())
+    }
+
+    // Merges the runs a few at a time until they can all be merged at once
+    fn sorted(mut self, dir: &Path) -> io::Result<Merge> {
+        self.flush(dir)?;
+        let mut runs = self.runs;
+        while runs.len() > FAN_IN {
+            let mut merged = Vec::new();
+            while !runs.is_empty() {
+                let group = runs.drain(..FAN_IN.min(runs.len())).collect();
+                merged.push(Run::write(dir, Merge::new(group)?)?);
+            }
+            runs = merged;
+        }
+        Merge::new(runs)
+    }
+}
+
+/// The same as `distance_matrix` on the lists read by `parse_lines`, but for lists much larger
+/// than memory. `path` is read a line at a time, no more than `run_len` IDs from each column are
+/// held at once, and the rest are sorted in temporary files under `dir`.
+pub fn external_distance_matrix(
+    path: &Path,
+    dir: &Path,
+    run_len: usize,
+) -> Result<Vec<Vec<u128>>, ExternalError> {
+    let run_len = run_len.max(1);
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut columns: Vec<Column> = Vec::new();
+    let mut line = String::new();
+    let mut i = 0;
+    while reader.read_line(&mut line)? > 0 {
+        let text = line.trim_end_matches(['\n', '\r']);
+        if columns.is_empty() {
+            columns = (0..width(Some(text)))
+                .map(|_| Column {
+                    buffer: Vec::new(),
+                    runs: Vec::new(),
+                })
+                .collect();
+        }
+        let row = parse_row(i, text, columns.len())?;
+        for (column, id) in columns.iter_mut().zip(row) {
+            column.buffer.push(id);
+            if column.buffer.len() == run_len {
+                column.flush(dir)?;
+            }
+        }
+        line.clear();
+        i += 1;
+    }
+    if columns.is_empty() {
+        return Ok(vec![vec![0; 2]; 2]);
+    }
+
+    // Every line has an ID in every column, so the columns all run out together
+    let mut merges = columns
+        .into_iter()
+        .map(|column| column.sorted(dir))
+        .collect::<io::Result<Vec<_>>>()?;
+    let mut matrix = vec![vec![0; merges.len()]; merges.len()];
+    let mut ids = vec![0; merges.len()];
+    for _ in 0..i {
+        for (id, merge) in ids.iter_mut().zip(&mut merges) {
+            *id = merge.next().expect("Columns are the same length")?;
+        }
+        for (a, row) in matrix.iter_mut().enumerate() {
+            for (b, total) in row.iter_mut().enumerate() {
+                *total += u128::from(ids[a].abs_diff(ids[b]));
+            }
+        }
+    }
+    Ok(matrix)
+}
+
+#[test]
+fn test_external_distance_matrix() {
+    use crate::{distance_matrix, generate::generate, parse_lines};
+    use aoc_common::Rng;
+
+    let dir = std::env::temp_dir();
+    let path = dir.join(format!("aoc01-external-{}.txt", process::id()));
+    // Enough runs of 2 IDs that they have to be merged in more than one pass
+    let mut lines = generate(&mut Rng::new(1), 3 * FAN_IN);
+    lines[0] += " -7";
+    for line in &mut lines[1..] {
+        *line += " 12";
+    }
+    fs::write(&path, lines.join("\n")).unwrap();
+
+    let expected = distance_matrix(&mut parse_lines(&lines).unwrap()).unwrap();
+    assert_eq!(expected.len(), 3);
+    for run_len in [2, RUN_LEN] {
+        assert_eq!(
+            external_distance_matrix(&path, &dir, run_len).unwrap(),
+            expected
+        );
+    }
+
+    fs::write(&path, "1 2\n3 x\n").unwrap();
+    match external_distance_matrix(&path, &dir, 1) {
+        Err(ExternalError::Parse(e)) => assert_eq!((e.line, e.column), (2, 3)),
+        result => panic!("Expected a parse error, got {result:?}"),
+    }
+    fs::remove_file(&path).unwrap();
+}
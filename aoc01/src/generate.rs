@@ -0,0 +1,18 @@
+// Random location lists: `size` lines of two 5 digit location IDs, like the real input. The right
+// list reuses some IDs from the left so that part 2 has similarity to find.
+
+use aoc_common::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let left: Vec<i64> = (0..size).map(|_| rng.range(10000..100000)).collect();
+    (0..size)
+        .map(|i| {
+            let right = if rng.chance(0.3) {
+                *rng.choose(&left)
+            } else {
+                rng.range(10000..100000)
+            };
+            format!("{}   {right}", left[i])
+        })
+        .collect()
+}
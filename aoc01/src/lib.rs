@@ -0,0 +1,264 @@
+//! Day 1: Historian Hysteria. Compares two lists of location IDs.
+
+pub mod external;
+pub mod generate;
+pub mod report;
+
+#[cfg(test)]
+use aoc_common::{check, fixture, shrink_vec};
+use aoc_common::{nth_token, parse_token, trace, Answer, ParseError, Solution};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+/// A location ID. The puzzle's are all positive, but other lists can have negative ones too.
+pub type Id = i64;
+
+// How many columns a list has, which is decided by its first line
+fn width(first: Option<&str>) -> usize {
+    first.map_or(2, |line| line.split_whitespace().count().max(2))
+}
+
+// The IDs on a single line, which has to have exactly `width` of them
+fn parse_row(i: usize, line: &str, width: usize) -> Result<Vec<Id>, ParseError> {
+    let l: Vec<&str> = line.split_whitespace().collect();
+    if let Some(extra) = l.get(width) {
+        return Err(ParseError::new(i, line, extra, "Too many columns"));
+    }
+    (0..width)
+        .map(|c| parse_token(i, line, nth_token(i, line, &l, c)?))
+        .collect()
+}
+
+/// Reads every column of location IDs into its own list. The first line decides how many columns
+/// there are, which has to be at least two, and every other line must have the same number.
+pub fn parse_lines(lines: &[String]) -> Result<Vec<Vec<Id>>, ParseError> {
+    let width = width(lines.first().map(String::as_str));
+    let mut columns: Vec<Vec<Id>> = vec![Vec::new(); width];
+    for (i, line) in lines.iter().enumerate() {
+        for (column, id) in columns.iter_mut().zip(parse_row(i, line, width)?) {
+            column.push(id);
+        }
+    }
+    Ok(columns)
+}
+
+/// Two lists that can't be paired up, because they're different lengths.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LengthMismatch {
+    pub left: usize,
+    pub right: usize,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "Lists have different lengths: {} and {}",
+            self.left, self.right
+        )
+    }
+}
+
+impl Error for LengthMismatch {}
+
+fn check_lengths(v1: &[Id], v2: &[Id]) -> Result<(), LengthMismatch> {
+    if v1.len() != v2.len() {
+        return Err(LengthMismatch {
+            left: v1.len(),
+            right: v2.len(),
+        });
+    }
+    Ok(())
+}
+
+// The distance between two lists that are already sorted. Each pair is less than 2^64 apart and
+// there are fewer than 2^64 pairs, so the total can't overflow 128 bits.
+fn sorted_diff(v1: &[Id], v2: &[Id]) -> Result<u128, LengthMismatch> {
+    check_lengths(v1, v2)?;
+    Ok(v1
+        .iter()
+        .zip(v2)
+        .map(|(&a, &b)| u128::from(a.abs_diff(b)))
+        .sum())
+}
+
+/// The total distance between two lists, pairing up the smallest IDs first. Sorts both lists
+/// in place.
+pub fn compute_sorted_diff(v1: &mut [Id], v2: &mut [Id]) -> Result<u128, LengthMismatch> {
+    v1.sort();
+    v2.sort();
+    trace!("v1: {v1:?}");
+    trace!("v2: {v2:?}");
+    sorted_diff(v1, v2)
+}
+
+/// The distance between every pair of lists, where row `i` column `j` is the distance between
+/// lists `i` and `j`. Sorts every list in place.
+pub fn distance_matrix(columns: &mut [Vec<Id>]) -> Result<Vec<Vec<u128>>, LengthMismatch> {
+    for column in columns.iter_mut() {
+        column.sort();
+    }
+    columns
+        .iter()
+        .map(|a| columns.iter().map(|b| sorted_diff(a, b)).collect())
+        .collect()
+}
+
+// Adds `id` seen `count` times to a similarity. A single product always fits in 128 bits, but
+// enough of them added up might not.
+fn add_similarity(similarity: i128, id: Id, count: i64) -> Result<i128, String> {
+    similarity
+        .checked_add(i128::from(id) * i128::from(count))
+        .ok_or_else(|| "Similarity overflows".to_string())
+}
+
+/// Adds up each ID in the left list multiplied by how often it appears in the right list. The
+/// lists don't need to be the same length. Fails if the total overflows.
+pub fn compute_similarity(v1: &[Id], v2: &[Id]) -> Result<i128, String> {
+    let mut similarity = 0;
+    for vl in v1 {
+        let count = v2.iter().filter(|&x| x == vl).count();
+        similarity = add_similarity(similarity, *vl, count as i64)?;
+    }
+    Ok(similarity)
+}
+
+// How many times each ID appears in a list
+fn count_ids(v: &[Id]) -> HashMap<Id, i64> {
+    let mut counts: HashMap<Id, i64> = HashMap::new();
+    for &id in v {
+        *counts.entry(id).or_default() += 1;
+    }
+    counts
+}
+
+/// The same similarity as `compute_similarity`, but counting the right list up front so that it
+/// takes linear time instead of quadratic.
+pub fn compute_similarity_counts(v1: &[Id], v2: &[Id]) -> Result<i128, String> {
+    let counts = count_ids(v2);
+    v1.iter().try_fold(0, |similarity, v| {
+        add_similarity(similarity, *v, counts.get(v).copied().unwrap_or(0))
+    })
+}
+
+/// Which way to work out the similarity. Both give the same answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Similarity {
+    /// `compute_similarity`, which scans the right list for every ID in the left.
+    Scan,
+    /// `compute_similarity_counts`, which counts the right list once.
+    Counts,
+}
+
+impl Similarity {
+    /// The similarity of `v1` and `v2`, worked out this way.
+    pub fn compute(self, v1: &[Id], v2: &[Id]) -> Result<i128, String> {
+        match self {
+            Similarity::Scan => compute_similarity(v1, v2),
+            Similarity::Counts => compute_similarity_counts(v1, v2),
+        }
+    }
+}
+
+/// Day 1: Historian Hysteria.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+
+    type Parsed = Vec<Vec<Id>>;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        parse_lines(lines)
+    }
+
+    // Only the first two lists count for the puzzle, and parsing made sure they're the same length
+    fn part1(mut parsed: Self::Parsed) -> Answer {
+        let [v1, v2, ..] = &mut parsed[..] else {
+            unreachable!("There are always at least two lists");
+        };
+        compute_sorted_diff(v1, v2).unwrap().into()
+    }
+
+    // The scan is only there to check against, since it's far too slow for big lists
+    fn part2(parsed: Self::Parsed) -> Answer {
+        Similarity::Counts.compute(&parsed[0], &parsed[1]).into()
+    }
+}
+
+#[test]
+fn test_similarity_counts() {
+    for file in [fixture!("prelim.txt"), fixture!("input.txt")] {
+        let vs = parse_lines(&file).unwrap();
+        let scan = Similarity::Scan.compute(&vs[0], &vs[1]);
+        assert_eq!(Similarity::Counts.compute(&vs[0], &vs[1]), scan);
+    }
+    let vs = parse_lines(&fixture!("prelim.txt")).unwrap();
+    assert_eq!(Similarity::Counts.compute(&vs[0], &vs[1]), Ok(31));
+}
+
+#[test]
+fn test_large_ids() {
+    // Far past what 64 bits can hold, both for the distance and the similarity
+    let (v1, v2) = (vec![Id::MIN, Id::MIN], vec![Id::MAX, Id::MAX]);
+    let expected = 2 * u128::from(Id::MIN.abs_diff(Id::MAX));
+    assert_eq!(sorted_diff(&v1, &v2), Ok(expected));
+    let similarity = 4 * i128::from(Id::MAX);
+    assert_eq!(compute_similarity(&v2, &v2), Ok(similarity));
+    assert_eq!(compute_similarity_counts(&v2, &v2), Ok(similarity));
+    assert_eq!(
+        compute_similarity_counts(&v1, &v1),
+        Ok(4 * i128::from(Id::MIN))
+    );
+}
+
+#[test]
+fn test_similarity_counts_random() {
+    // Small IDs, so that plenty of them repeat
+    check(
+        100,
+        |rng| {
+            (0..rng.below(20))
+                .map(|_| (rng.range(-5..5), rng.range(-5..5)))
+                .collect::<Vec<_>>()
+        },
+        |pairs| shrink_vec(pairs),
+        |pairs| {
+            let (v1, v2): (Vec<Id>, Vec<Id>) = pairs.iter().copied().unzip();
+            Similarity::Scan.compute(&v1, &v2) == Similarity::Counts.compute(&v1, &v2)
+        },
+    );
+}
+
+#[test]
+fn test_distance_matrix() {
+    let lines = ["-3 4 0", "4 -3 0", "2 5 0"];
+    let lines = lines.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+    let mut columns = parse_lines(&lines).unwrap();
+    let matrix = distance_matrix(&mut columns).unwrap();
+    assert_eq!(matrix, [[0, 3, 9], [3, 0, 12], [9, 12, 0]]);
+
+    let err = compute_sorted_diff(&mut [1, 2], &mut [1]).unwrap_err();
+    assert_eq!(err, LengthMismatch { left: 2, right: 1 });
+    assert!(distance_matrix(&mut [vec![1], vec![2], vec![]]).is_err());
+}
+
+#[test]
+fn test_ragged_columns() {
+    let lines = ["1 2 3", "4 5", "6 7 8"];
+    let lines = lines.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+    let err = parse_lines(&lines).unwrap_err();
+    assert_eq!(
+        (err.line, err.column, err.message.as_str()),
+        (2, 4, "Missing token")
+    );
+
+    let lines = ["1 2", "3 4 5"];
+    let lines = lines.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+    let err = parse_lines(&lines).unwrap_err();
+    assert_eq!(
+        (err.line, err.column, err.message.as_str()),
+        (2, 5, "Too many columns")
+    );
+}
@@ -1,86 +1,81 @@
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
+use aoc01::external::{external_distance_matrix, RUN_LEN};
+use aoc01::report::{Report, TOP_K};
+use aoc01::{parse_lines, Day01, Similarity};
+use aoc_common::{get_input, run_day};
+use std::env;
+use std::fmt::Display;
+use std::path::Path;
+use std::process;
 
-fn get_input(filename: &str) -> Vec<String> {
-    let file = File::open(filename).unwrap();
-    let reader = BufReader::new(file);
-    let mut lines: Vec<String> = Vec::new();
-    for line in reader.lines() {
-        lines.push(line.unwrap());
-    }
-
-    lines
+// Scripts need to be able to tell when a run didn't work
+fn fail(e: impl Display) -> ! {
+    eprintln!("{e}");
+    process::exit(1);
 }
 
-fn parse_lines(lines: &Vec<String>) -> (Vec<u32>, Vec<u32>) {
-    let mut v1: Vec<u32> = Vec::new();
-    let mut v2: Vec<u32> = Vec::new();
-    for line in lines {
-        let l: Vec<&str> = line.split_whitespace().collect();
-        v1.push(l[0].parse::<u32>().unwrap());
-        v2.push(l[1].parse::<u32>().unwrap());
-    }
-    (v1, v2)
+// Breaks down the totals for the first two lists in `path`, with the `top_k` furthest apart pairs
+fn report(path: &str, top_k: usize) -> Result<Report, String> {
+    let lines = get_input(path).map_err(|e| format!("{path}: {e}"))?;
+    let mut vs = parse_lines(&lines).map_err(|e| e.in_file(path).to_string())?;
+    let [v1, v2, ..] = &mut vs[..] else {
+        unreachable!("There are always at least two lists");
+    };
+    Report::new(v1, v2, top_k).map_err(|e| format!("{path}: {e}"))
 }
 
-fn compute_sorted_diff(vs: &mut (Vec<u32>, Vec<u32>)) -> u32 {
-    let mut diff = 0;
-    let v1 = &mut vs.0;
-    let v2 = &mut vs.1;
-    v1.sort();
-    v2.sort();
-    //println!("v1: {:?}", v1);
-    //println!("v2: {:?}", v2);
-
-    assert!(v1.len() == v2.len());
-    let num_lines = v1.len();
-    for i in 0..num_lines {
-        diff += u32::abs_diff(v1[i], v2[i]);
-    }
-    println!("diff: {}", diff);
-    diff
-}
-
-fn compute_similarity(vs: (Vec<u32>, Vec<u32>)) -> u32 {
-    let mut similarity = 0;
-    let v1 = vs.0;
-    let v2 = vs.1;
-
-    assert!(v1.len() == v2.len());
-    for vl in &v1 {
-        similarity += v2.iter().filter(|&x| x == vl).sum::<u32>();
-    }
-    println!("similarity: {}", similarity);
-    similarity
-}
-
-#[test]
-fn test_prelim() {
-    let diff = compute_sorted_diff(&mut parse_lines(&get_input("prelim.txt")));
-    assert_eq!(diff, 11);
-}
-
-#[test]
-fn test_prelim2() {
-    let similarity = compute_similarity(parse_lines(&get_input("prelim.txt")));
-    assert_eq!(similarity, 31);
-}
-#[test]
-fn test_part1() {
-    let diff = compute_sorted_diff(&mut parse_lines(&get_input("input.txt")));
-    assert_eq!(diff, 1222801);
-}
-
-#[test]
-fn test_part2() {
-    let similarity = compute_similarity(parse_lines(&get_input("input.txt")));
-    assert_eq!(similarity, 22545250);
+// Part 2 for the first two lists in `path`, worked out with `method`
+fn similarity(path: &str, method: Similarity) -> Result<i128, String> {
+    let lines = get_input(path).map_err(|e| format!("{path}: {e}"))?;
+    let vs = parse_lines(&lines).map_err(|e| e.in_file(path).to_string())?;
+    method
+        .compute(&vs[0], &vs[1])
+        .map_err(|e| format!("{path}: {e}"))
 }
 
 fn main() {
-    compute_sorted_diff(&mut parse_lines(&get_input("prelim.txt")));
-    compute_sorted_diff(&mut parse_lines(&get_input("input.txt")));
-    compute_similarity(parse_lines(&get_input("prelim.txt")));
-    compute_similarity(parse_lines(&get_input("input.txt")));
+    // `--external <path>` sorts the lists on disk, for ones that are too big to read in,
+    // `--report <path> [top]` shows which pairs and IDs make up the totals, and
+    // `--similarity <scan|counts> <path>` picks how part 2 is worked out
+    let args: Vec<String> = env::args().skip(1).collect();
+    match &args[..] {
+        [flag, path] if flag == "--external" => {
+            match external_distance_matrix(Path::new(path), &env::temp_dir(), RUN_LEN) {
+                Ok(matrix) => println!("Day 1 part 1 ({path}): {}", matrix[0][1]),
+                Err(e) => fail(format!("{path}: {e}")),
+            }
+        }
+        [flag, path, top @ ..] if flag == "--report" && top.len() <= 1 => {
+            let top_k = match top.first().map(|k| k.parse()) {
+                None => TOP_K,
+                Some(Ok(k)) => k,
+                Some(Err(_)) => fail(format!("Invalid number of pairs: {}", top[0])),
+            };
+            // An overflowing similarity is shown in the report's total, but still fails the run
+            match report(path, top_k) {
+                Ok(report) => {
+                    print!("{report}");
+                    if let Err(e) = report.similarity() {
+                        fail(format!("{path}: {e}"));
+                    }
+                }
+                Err(e) => fail(e),
+            }
+        }
+        [flag, method, path] if flag == "--similarity" => {
+            let method = match method.as_str() {
+                "scan" => Similarity::Scan,
+                "counts" => Similarity::Counts,
+                _ => fail(format!("Invalid similarity method: {method}")),
+            };
+            match similarity(path, method) {
+                Ok(similarity) => println!("Day 1 part 2 ({path}): {similarity}"),
+                Err(e) => fail(e),
+            }
+        }
+        _ => run_day::<Day01>(
+            env!("CARGO_MANIFEST_DIR"),
+            &["prelim.txt", "input.txt"],
+            &["prelim.txt", "input.txt"],
+        ),
+    }
 }
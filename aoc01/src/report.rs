@@ -0,0 +1,151 @@
+// A breakdown of the totals for two location lists, so it's easy to see which IDs are behind them.
+// It's written as tab separated tables, one after the other with a blank line in between.
+
+use crate::{add_similarity, check_lengths, count_ids, Id, LengthMismatch};
+use std::cmp::Reverse;
+use std::fmt;
+
+/// How many of the most divergent pairs a report lists by default.
+pub const TOP_K: usize = 10;
+
+/// A pair of IDs that were matched up after sorting, and how far apart they are.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Pair {
+    pub left: Id,
+    pub right: Id,
+    pub distance: u64,
+}
+
+/// Every pair that goes into the total distance, the pairs that add the most to it, and how each
+/// ID in the left list adds to the similarity.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Report {
+    /// The pairs in sorted order.
+    pub pairs: Vec<Pair>,
+    /// Indexes into `pairs` of the most divergent ones, furthest apart first.
+    pub top: Vec<usize>,
+    /// Each ID in the left list, in its original order, with how often it's in the right list.
+    pub counts: Vec<(Id, i64)>,
+}
+
+impl Report {
+    /// Breaks down the totals for `v1` and `v2`, listing the `top_k` pairs that are furthest
+    /// apart. Like `compute_sorted_diff`, it sorts both lists in place.
+    pub fn new(v1: &mut [Id], v2: &mut [Id], top_k: usize) -> Result<Self, LengthMismatch> {
+        check_lengths(v1, v2)?;
+        let right = count_ids(v2);
+        let counts = v1
+            .iter()
+            .map(|&id| (id, right.get(&id).copied().unwrap_or(0)))
+            .collect();
+
+        v1.sort();
+        v2.sort();
+        let pairs: Vec<Pair> = v1
+            .iter()
+            .zip(v2.iter())
+            .map(|(&left, &right)| Pair {
+                left,
+                right,
+                distance: left.abs_diff(right),
+            })
+            .collect();
+        // Ties stay in sorted order
+        let mut top: Vec<usize> = (0..pairs.len()).collect();
+        top.sort_by_key(|&i| Reverse(pairs[i].distance));
+        top.truncate(top_k);
+
+        Ok(Self { pairs, top, counts })
+    }
+
+    /// The total distance, which is the same as `compute_sorted_diff` gives.
+    pub fn distance(&self) -> u128 {
+        self.pairs
+            .iter()
+            .map(|pair| u128::from(pair.distance))
+            .sum()
+    }
+
+    /// The similarity, which is the same as `compute_similarity` gives, overflow included.
+    pub fn similarity(&self) -> Result<i128, String> {
+        self.counts.iter().try_fold(0, |similarity, &(id, count)| {
+            add_similarity(similarity, id, count)
+        })
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        // Pairs are numbered from 1 in sorted order, in both tables
+        let pair = |fmt: &mut fmt::Formatter, i: usize| {
+            let Pair {
+                left,
+                right,
+                distance,
+            } = self.pairs[i];
+            writeln!(fmt, "{}\t{left}\t{right}\t{distance}", i + 1)
+        };
+
+        writeln!(fmt, "pair\tleft\tright\tdistance")?;
+        for i in 0..self.pairs.len() {
+            pair(fmt, i)?;
+        }
+        writeln!(fmt, "total\t\t\t{}", self.distance())?;
+
+        writeln!(fmt)?;
+        writeln!(fmt, "top\tleft\tright\tdistance")?;
+        for &i in &self.top {
+            pair(fmt, i)?;
+        }
+
+        writeln!(fmt)?;
+        writeln!(fmt, "left\tcount\tsimilarity")?;
+        for &(id, count) in &self.counts {
+            writeln!(fmt, "{id}\t{count}\t{}", i128::from(id) * i128::from(count))?;
+        }
+        match self.similarity() {
+            Ok(similarity) => writeln!(fmt, "total\t\t{similarity}"),
+            Err(e) => writeln!(fmt, "total\t\t{e}"),
+        }
+    }
+}
+
+#[test]
+fn test_report() {
+    use crate::{compute_similarity, parse_lines};
+    use aoc_common::fixture;
+
+    let mut vs = parse_lines(&fixture!("prelim.txt")).unwrap();
+    let similarity = compute_similarity(&vs[0], &vs[1]).unwrap();
+    let [v1, v2, ..] = &mut vs[..] else {
+        unreachable!("There are always at least two lists");
+    };
+    let report = Report::new(v1, v2, 2).unwrap();
+    assert_eq!(
+        (report.distance(), report.similarity()),
+        (11, Ok(similarity))
+    );
+    // The last pair is (4, 9), and the first and fifth are the next furthest apart at 2
+    assert_eq!(report.top, [5, 0]);
+    assert_eq!(report.counts[..3], [(3, 3), (4, 1), (2, 0)]);
+
+    let text = report.to_string();
+    assert!(text.contains("\n6\t4\t9\t5\n1\t1\t3\t2\n\n"));
+    assert!(text.ends_with("3\t3\t9\ntotal\t\t31\n"));
+
+    assert!(Report::new(&mut [1], &mut [], TOP_K).is_err());
+}
+
+#[test]
+fn test_report_overflows() {
+    // Each product fits in 128 bits, but three of them added up don't
+    let report = Report {
+        pairs: Vec::new(),
+        top: Vec::new(),
+        counts: vec![(Id::MAX, i64::MAX); 3],
+    };
+    assert!(report.similarity().is_err());
+    assert!(report
+        .to_string()
+        .ends_with("total\t\tSimilarity overflows\n"));
+}
@@ -0,0 +1,29 @@
+// Random reports: `size` lines of 5 to 8 levels each. Most start out safe, and then some have
+// one or two levels disturbed so that the problem dampener has something to do.
+
+use aoc_common::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let len = rng.below(4) + 5;
+            let dir = if rng.chance(0.5) { 1 } else { -1 };
+            // Far enough from 0 that 8 levels going down stay positive
+            let mut level = rng.range(25..75);
+            let mut levels = Vec::with_capacity(len);
+            for _ in 0..len {
+                levels.push(level);
+                level += dir * rng.range(1..4);
+            }
+            for _ in 0..rng.below(3) {
+                let i = rng.below(len);
+                levels[i] = (levels[i] + rng.range(-5..6)).max(1);
+            }
+            levels
+                .iter()
+                .map(|l| l.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect()
+}
@@ -0,0 +1,176 @@
+//! Day 2: Red-Nosed Reports. Checks reactor reports for levels that change safely.
+
+pub mod generate;
+
+#[cfg(test)]
+use aoc_common::fixture;
+use aoc_common::{parse_token, trace, Answer, ParseError, Solution};
+
+/// Reads each report as a list of levels, which needs at least 2 levels.
+pub fn parse_lines(lines: &[String]) -> Result<Vec<Vec<u32>>, ParseError> {
+    let mut rows: Vec<Vec<u32>> = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let mut row: Vec<u32> = Vec::new();
+        for x in line.split_whitespace() {
+            row.push(parse_token(i, line, x)?);
+        }
+        if row.len() < 2 {
+            return Err(ParseError::new(i, line, line, "Expected at least 2 levels"));
+        }
+        rows.push(row);
+    }
+    trace!("{rows:?}");
+    Ok(rows)
+}
+
+/// Which way a report's levels have to go.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Trend {
+    Increasing,
+    Decreasing,
+    // Whichever way the first change goes, as long as the rest follow it
+    Either,
+}
+
+/// What makes a report safe. Each change between neighboring levels has to be between `min_step`
+/// and `max_step`, and every change has to go the same way. Neighbors that are equal don't change
+/// at all, so they're only safe if `allow_equal` is set.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SafetyPolicy {
+    pub min_step: u32,
+    pub max_step: u32,
+    pub allow_equal: bool,
+    pub trend: Trend,
+}
+
+impl SafetyPolicy {
+    /// The puzzle's reactor, where levels all increase or all decrease by 1 to 3 at a time.
+    pub const PUZZLE: Self = Self {
+        min_step: 1,
+        max_step: 3,
+        allow_equal: false,
+        trend: Trend::Either,
+    };
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        Self::PUZZLE
+    }
+}
+
+/// Whether a report's levels change the way `policy` says they have to.
+pub fn check_safety(row: &[u32], policy: &SafetyPolicy) -> bool {
+    let mut trend = policy.trend;
+    for pair in row.windows(2) {
+        let diff = pair[1] as i64 - pair[0] as i64;
+        if diff == 0 {
+            if !policy.allow_equal {
+                return false;
+            }
+            continue;
+        }
+        if !(policy.min_step..=policy.max_step).contains(&(diff.unsigned_abs() as u32)) {
+            return false;
+        }
+        let up = diff > 0;
+        match trend {
+            Trend::Increasing if !up => return false,
+            Trend::Decreasing if up => return false,
+            Trend::Either if up => trend = Trend::Increasing,
+            Trend::Either => trend = Trend::Decreasing,
+            _ => {}
+        }
+    }
+    true
+}
+
+/// Counts the safe reports.
+pub fn compute_safe(rows: &[Vec<u32>], policy: &SafetyPolicy) -> u32 {
+    let mut safe = 0;
+    for row in rows {
+        if check_safety(row, policy) {
+            safe += 1;
+        }
+    }
+    safe
+}
+
+/// Counts the reports that are safe once at most one level is removed.
+pub fn compute_safe_with_problem_dampener(rows: &[Vec<u32>], policy: &SafetyPolicy) -> u32 {
+    let mut safe = 0;
+    for row in rows {
+        if check_safety(row, policy) {
+            safe += 1;
+        } else {
+            // Just clone a duplicate row and remove an item to try them
+            let len = row.len();
+            let mut i = 0;
+            let mut done = false;
+            while i < len && !done {
+                let mut new_row = row.clone();
+                new_row.remove(i);
+                if check_safety(&new_row, policy) {
+                    safe += 1;
+                    done = true;
+                }
+                i += 1;
+            }
+        }
+    }
+    safe
+}
+
+/// Day 2: Red-Nosed Reports.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+
+    type Parsed = Vec<Vec<u32>>;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        parse_lines(lines)
+    }
+
+    fn part1(parsed: Self::Parsed) -> Answer {
+        compute_safe(&parsed, &SafetyPolicy::PUZZLE).into()
+    }
+
+    fn part2(parsed: Self::Parsed) -> Answer {
+        compute_safe_with_problem_dampener(&parsed, &SafetyPolicy::PUZZLE).into()
+    }
+}
+
+#[test]
+fn test_safety_policy() {
+    let rows = parse_lines(&fixture!("prelim.txt")).unwrap();
+    let puzzle = SafetyPolicy::default();
+    assert_eq!(compute_safe(&rows, &puzzle), 2);
+    assert_eq!(compute_safe_with_problem_dampener(&rows, &puzzle), 4);
+
+    // "8 6 4 4 1" has a 3 step down once it's allowed to stay level
+    let level = SafetyPolicy {
+        allow_equal: true,
+        ..puzzle
+    };
+    assert!(check_safety(&rows[4], &level));
+    assert_eq!(compute_safe(&rows, &level), 3);
+
+    let up = SafetyPolicy {
+        trend: Trend::Increasing,
+        ..puzzle
+    };
+    assert!(!check_safety(&rows[0], &up));
+    assert!(check_safety(&rows[5], &up));
+
+    // Steps of 1 are too small now, and both "1 3 6 7 9" and "1 2 7 8 9" have one
+    let wide = SafetyPolicy {
+        min_step: 2,
+        max_step: 5,
+        ..puzzle
+    };
+    assert!(!check_safety(&rows[5], &wide));
+    assert!(!check_safety(&rows[1], &wide));
+    assert!(check_safety(&[9, 4, 2], &wide));
+}
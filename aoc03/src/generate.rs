@@ -0,0 +1,36 @@
+// Random corrupted memory: `size` instructions scattered through junk, wrapped onto lines of
+// about 3000 characters like the real input. Some of the instructions are deliberately broken.
+
+use aoc_common::Rng;
+
+const JUNK: &[&str] = &[
+    "what()", "who()", "from()", "select()", "where()", "how()", "why()", "mul[", "mul (", "%",
+    "!", "@", "^", "*", ")", "[", "]", "{", "}", "<", ">", "?", ";", ":", "'", " ", ",", "$",
+];
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for _ in 0..size {
+        let instruction = match rng.below(10) {
+            0 => "do()".to_string(),
+            1 => "don't()".to_string(),
+            // Too many digits, or a stray space, means it isn't a mul at all
+            2 => format!("mul({},{})", rng.range(1000..10000), rng.range(1..1000)),
+            3 => format!("mul({}, {})", rng.range(1..1000), rng.range(1..1000)),
+            _ => format!("mul({},{})", rng.range(1..1000), rng.range(1..1000)),
+        };
+        line.push_str(&instruction);
+        for _ in 0..rng.below(4) {
+            let junk = rng.below(JUNK.len());
+            line.push_str(JUNK[junk]);
+        }
+        if line.len() > 3000 {
+            lines.push(std::mem::take(&mut line));
+        }
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
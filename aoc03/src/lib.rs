@@ -0,0 +1,274 @@
+//! Day 3: Mull It Over. Adds up the `mul(a,b)` instructions hidden in corrupted memory.
+
+pub mod generate;
+
+#[cfg(test)]
+use aoc_common::{check, fixture, shrink_string, shrink_vec, Rng};
+use aoc_common::{Answer, Input, ParseError, Solution};
+use regex::Regex;
+
+// A sum of products of numbers up to 3 digits. The puzzle input fits in 32 bits, but a scaled-up
+// one doesn't take many lines to overflow that, so the `checked` feature makes it 64 bits, which
+// no input that fits in memory can overflow. Without it, a sum that overflows fails the part.
+#[cfg(feature = "checked")]
+pub type Sum = u64;
+#[cfg(not(feature = "checked"))]
+pub type Sum = u32;
+
+#[derive(PartialEq)]
+enum CheckEnabled {
+    Checked,
+    Unchecked,
+}
+
+// This was my first solution to part 1, which works nicely, but I needed a better parser for part 2
+#[allow(dead_code)]
+fn sum_of_multiplies_regex(lines: &[String]) -> Sum {
+    let mut sum = 0;
+    let re = Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)").unwrap();
+
+    for line in lines {
+        for (_, [lhs, rhs]) in re.captures_iter(line).map(|c| c.extract()) {
+            let l = lhs.parse::<u32>().unwrap();
+            let r = rhs.parse::<u32>().unwrap();
+            sum += Sum::from(l * r);
+        }
+    }
+    sum
+}
+
+fn parse_up_to_3_digits(bytes: &[u8], pos: usize) -> Option<(u32, usize)> {
+    let mut set = false;
+    let mut val = 0;
+    let mut new_pos = pos;
+    let len = bytes.len();
+    // We are only allowed to accept 1-3 digits
+    while new_pos < pos + 3 {
+        if new_pos >= len {
+            return None;
+        }
+
+        match bytes[new_pos] {
+            b'0'..=b'9' => {
+                val = val * 10 + u32::from(bytes[new_pos] - b'0');
+                set = true;
+                new_pos += 1;
+            }
+            _ => {
+                if set {
+                    return Some((val, new_pos));
+                } else {
+                    return None;
+                }
+            }
+        }
+    }
+    Some((val, new_pos))
+}
+
+// Returns new cursor position if we parsed "do()"
+fn parse_do(bytes: &[u8], pos: usize) -> Option<usize> {
+    if pos + 3 < bytes.len()
+        && bytes[pos] == b'd'
+        && bytes[pos + 1] == b'o'
+        && bytes[pos + 2] == b'('
+        && bytes[pos + 3] == b')'
+    {
+        Some(pos + 4)
+    } else {
+        None
+    }
+}
+
+// Returns new cursor position if we parsed "don't()"
+fn parse_dont(bytes: &[u8], pos: usize) -> Option<usize> {
+    if pos + 6 < bytes.len()
+        && bytes[pos] == b'd'
+        && bytes[pos + 1] == b'o'
+        && bytes[pos + 2] == b'n'
+        && bytes[pos + 3] == b'\''
+        && bytes[pos + 4] == b't'
+        && bytes[pos + 5] == b'('
+        && bytes[pos + 6] == b')'
+    {
+        Some(pos + 7)
+    } else {
+        None
+    }
+}
+
+// Returns product and new cursor position if we parsed the regex r"mul\([0-9]{1,3},[0-9]{1,3}\)"
+fn parse_mul(bytes: &[u8], pos: usize) -> Option<(u32, usize)> {
+    let len = bytes.len();
+
+    // Look at pos + 7 since we minimally need to handle at least "mul(1,1)"
+    if pos + 7 >= len
+        || bytes[pos] != b'm'
+        || bytes[pos + 1] != b'u'
+        || bytes[pos + 2] != b'l'
+        || bytes[pos + 3] != b'('
+    {
+        return None;
+    }
+
+    // Time to parse some integers skipping "mul("
+    let (lhs, new_pos) = parse_up_to_3_digits(bytes, pos + 4)?;
+
+    // Still need to parse at least ",1)"
+    if new_pos + 3 > len || bytes[new_pos] != b',' {
+        return None;
+    }
+
+    // Skipping comma
+    let (rhs, new_pos) = parse_up_to_3_digits(bytes, new_pos + 1)?;
+
+    // Check for trailing parentheses before finishing
+    if new_pos >= len || bytes[new_pos] != b')' {
+        return None;
+    }
+
+    let product = lhs * rhs;
+    Some((product, new_pos + 1)) // Skipping rparen
+}
+
+// Newlines never match any of the instructions, so the whole input is scanned in one go
+fn sum_of_multiplies(memory: &[u8], check: CheckEnabled) -> Result<Sum, String> {
+    let mut sum = 0;
+    let mut enabled = true;
+    let len = memory.len();
+    let mut pos = 0;
+    while pos < len {
+        match memory[pos] {
+            b'd' => {
+                if let Some(new_pos) = parse_do(memory, pos) {
+                    enabled = true;
+                    pos = new_pos;
+                    continue;
+                } else if let Some(new_pos) = parse_dont(memory, pos) {
+                    // Only disable if we've enabled checked multiplies
+                    if check == CheckEnabled::Checked {
+                        enabled = false;
+                    }
+                    pos = new_pos;
+                    continue;
+                }
+            }
+            b'm' if enabled => {
+                if let Some((product, new_pos)) = parse_mul(memory, pos) {
+                    sum = Sum::checked_add(sum, Sum::from(product))
+                        .ok_or("Sum overflows (the `checked` feature makes it 64 bits)")?;
+                    pos = new_pos;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        // If we get here, we're done with the current token and just start at the next one
+        pos += 1;
+    }
+    Ok(sum)
+}
+
+/// Sums the products of every well formed `mul(a,b)` in the bytes of the input, unless the sum
+/// overflows.
+pub fn sum_of_all_multiplies(memory: &[u8]) -> Result<Sum, String> {
+    sum_of_multiplies(memory, CheckEnabled::Unchecked)
+}
+
+/// Like `sum_of_all_multiplies`, but `don't()` disables the multiplies that follow it until
+/// the next `do()`.
+pub fn sum_of_enabled_multiplies(memory: &[u8]) -> Result<Sum, String> {
+    sum_of_multiplies(memory, CheckEnabled::Checked)
+}
+
+/// Day 3: Mull It Over.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+
+    type Parsed = Input;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        Ok(Input::from_lines(lines))
+    }
+
+    // Cloning shares the bytes rather than copying them
+    fn parse_input(input: &Input) -> Result<Self::Parsed, ParseError> {
+        Ok(input.clone())
+    }
+
+    fn part1(parsed: Self::Parsed) -> Answer {
+        sum_of_all_multiplies(parsed.bytes()).into()
+    }
+
+    fn part2(parsed: Self::Parsed) -> Answer {
+        sum_of_enabled_multiplies(parsed.bytes()).into()
+    }
+}
+
+#[test]
+fn test_regex() {
+    for lines in [fixture!("prelim.txt"), fixture!("input.txt")] {
+        assert_eq!(
+            Ok(sum_of_multiplies_regex(&lines)),
+            sum_of_all_multiplies(Input::from_lines(&lines).bytes())
+        );
+    }
+}
+
+// Lines stitched together from pieces of valid and broken multiplies, so that both parsers see
+// plenty of near misses
+#[cfg(test)]
+fn random_lines(rng: &mut Rng) -> Vec<String> {
+    const PIECES: [&str; 10] = [
+        "mul(", "mul", "(", ",", ")", "do()", "don't()", "m", "x", " ",
+    ];
+    (0..rng.below(4))
+        .map(|_| {
+            let mut line = String::new();
+            for _ in 0..rng.below(40) {
+                match rng.below(4) {
+                    0 => line += &rng.below(2000).to_string(),
+                    1 => line += &format!("mul({},{})", rng.below(1000), rng.below(1000)),
+                    _ => line += PIECES[rng.below(PIECES.len())],
+                }
+            }
+            line
+        })
+        .collect()
+}
+
+#[test]
+fn test_regex_random() {
+    check(
+        500,
+        random_lines,
+        |lines| {
+            let mut smaller = shrink_vec(lines);
+            for (i, line) in lines.iter().enumerate() {
+                for shorter in shrink_string(line) {
+                    let mut candidate = lines.clone();
+                    candidate[i] = shorter;
+                    smaller.push(candidate);
+                }
+            }
+            smaller
+        },
+        |lines| {
+            Ok(sum_of_multiplies_regex(lines))
+                == sum_of_all_multiplies(Input::from_lines(lines).bytes())
+        },
+    );
+}
+
+#[test]
+fn test_sum_overflows() {
+    // Just past 32 bits
+    let memory = "mul(999,999)".repeat(5000);
+    let sum = sum_of_enabled_multiplies(memory.as_bytes());
+    #[cfg(feature = "checked")]
+    assert_eq!(sum, Ok(4990005000));
+    #[cfg(not(feature = "checked"))]
+    assert!(sum.is_err());
+}
@@ -0,0 +1,34 @@
+// A random `size` x `size` word search. Purely random letters rarely spell anything, so a few
+// copies of XMAS are planted in random directions first.
+
+use aoc_common::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let size = size.max(1);
+    let mut grid: Vec<Vec<char>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| *rng.choose(&['X', 'M', 'A', 'S']))
+                .collect()
+        })
+        .collect();
+
+    for _ in 0..size * size / 10 {
+        let (dx, dy) = (rng.range(-1..2), rng.range(-1..2));
+        let (x, y) = (rng.below(size) as i64, rng.below(size) as i64);
+        let (end_x, end_y) = (x + 3 * dx, y + 3 * dy);
+        if (dx, dy) == (0, 0) || !(0..size as i64).contains(&end_x) {
+            continue;
+        }
+        if !(0..size as i64).contains(&end_y) {
+            continue;
+        }
+        for (i, c) in "XMAS".chars().enumerate() {
+            let i = i as i64;
+            grid[(y + i * dy) as usize][(x + i * dx) as usize] = c;
+        }
+    }
+    grid.into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect()
+}
@@ -0,0 +1,211 @@
+//! Day 4: Ceres Search. Finds XMAS in a word search.
+
+pub mod generate;
+
+use aoc_common::{Answer, Coord, Grid, Input, ParseError, Solution};
+
+#[derive(Debug)]
+enum Direction {
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+    Up,
+    UpRight,
+}
+
+const DIRECTIONS: [Direction; 8] = [
+    Direction::Right,
+    Direction::DownRight,
+    Direction::Down,
+    Direction::DownLeft,
+    Direction::Left,
+    Direction::UpLeft,
+    Direction::Up,
+    Direction::UpRight,
+];
+
+/// A rectangular word search.
+pub struct Puzzle {
+    letters: Grid<u8>,
+    height: usize,
+    width: usize,
+}
+
+impl Puzzle {
+    /// Reads the word search, which must be rectangular and not empty.
+    pub fn new(input: &Input) -> Result<Self, ParseError> {
+        let lines = input.lines();
+        // Searching assumes a rectangular puzzle
+        let letters = Grid::from_bytes(&lines, None, Some)?;
+        if lines.is_empty() {
+            return Err(ParseError::end_of_input(&lines, "Empty puzzle"));
+        }
+        let height = letters.height();
+        let width = letters.width();
+        Ok(Self {
+            letters,
+            height,
+            width,
+        })
+    }
+
+    fn get_char(&self, pos: Coord) -> u8 {
+        self.letters[pos]
+    }
+
+    fn check_char(&self, c: u8, pos: Coord) -> bool {
+        self.get_char(pos) == c
+    }
+
+    fn search_start(&self, needle: &[u8], pos: Coord) -> u32 {
+        let (x, y) = pos;
+        let mut total = 0;
+
+        let height = self.height;
+        let width = self.width;
+        let needle_len = needle.len();
+
+        for dir in DIRECTIONS {
+            let mut valid = true;
+            // Check horizontal spread
+            match dir {
+                Direction::UpRight | Direction::Right | Direction::DownRight
+                    if x + needle_len > width =>
+                {
+                    valid = false;
+                }
+                Direction::UpLeft | Direction::Left | Direction::DownLeft if x + 1 < needle_len => {
+                    valid = false;
+                }
+                _ => {}
+            }
+            // Check vertical spread
+            match dir {
+                Direction::UpLeft | Direction::Up | Direction::UpRight if y + 1 < needle_len => {
+                    valid = false;
+                }
+                Direction::DownLeft | Direction::Down | Direction::DownRight
+                    if y + needle_len > height =>
+                {
+                    valid = false;
+                }
+                _ => {}
+            }
+            if valid {
+                let mut new_pos = pos;
+                for &c in needle {
+                    valid &= self.check_char(c, new_pos);
+                    new_pos = next_pos(new_pos, &dir);
+                }
+            }
+            if valid {
+                total += 1;
+            }
+        }
+
+        total
+    }
+
+    fn search_mas(&self, pos: Coord) -> bool {
+        let mut valid = true;
+        let cur = self.get_char(pos);
+        if cur != b'A' {
+            return false;
+        }
+
+        let up_left = self.get_char(next_pos(pos, &Direction::UpLeft));
+        let up_right = self.get_char(next_pos(pos, &Direction::UpRight));
+        let down_left = self.get_char(next_pos(pos, &Direction::DownLeft));
+        let down_right = self.get_char(next_pos(pos, &Direction::DownRight));
+
+        valid &= (up_left == b'M' && down_right == b'S') || (up_left == b'S' && down_right == b'M');
+        valid &= (up_right == b'M' && down_left == b'S') || (up_right == b'S' && down_left == b'M');
+
+        valid
+    }
+
+    /// Counts every XMAS, in any of the 8 directions.
+    pub fn search_xmas(&self) -> u32 {
+        let mut result = 0;
+        let needle = b"XMAS";
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if self.check_char(needle[0], (x, y)) {
+                    result += self.search_start(needle, (x, y));
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Counts every pair of MAS that cross diagonally in the shape of an X.
+    pub fn search_x_mas(&self) -> u32 {
+        let mut result = 0;
+
+        // Only have to walk the inner perimeter, since we are just trying to find the 'A' values,
+        // and then check for the X pattern.
+        for y in 1..self.height - 1 {
+            for x in 1..self.width - 1 {
+                if self.check_char(b'A', (x, y)) && self.search_mas((x, y)) {
+                    result += 1;
+                }
+            }
+        }
+
+        result
+    }
+}
+
+fn next_pos(pos: Coord, dir: &Direction) -> Coord {
+    let mut new_x = pos.0;
+    let mut new_y = pos.1;
+    match dir {
+        Direction::UpRight | Direction::Right | Direction::DownRight => {
+            new_x = new_x.saturating_add(1);
+        }
+        Direction::UpLeft | Direction::Left | Direction::DownLeft => {
+            new_x = new_x.saturating_sub(1);
+        }
+        _ => {}
+    }
+    match dir {
+        Direction::UpLeft | Direction::Up | Direction::UpRight => {
+            new_y = new_y.saturating_sub(1);
+        }
+        Direction::DownLeft | Direction::Down | Direction::DownRight => {
+            new_y = new_y.saturating_add(1);
+        }
+        _ => {}
+    }
+    (new_x, new_y)
+}
+
+/// Day 4: Ceres Search.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+
+    type Parsed = Puzzle;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        Puzzle::new(&Input::from_lines(lines))
+    }
+
+    fn parse_input(input: &Input) -> Result<Self::Parsed, ParseError> {
+        Puzzle::new(input)
+    }
+
+    fn part1(parsed: Self::Parsed) -> Answer {
+        parsed.search_xmas().into()
+    }
+
+    fn part2(parsed: Self::Parsed) -> Answer {
+        parsed.search_x_mas().into()
+    }
+}
@@ -0,0 +1,34 @@
+// Random page ordering rules and `size` updates. Like the real input, there's a rule for every
+// pair of pages, so each update has exactly one correct order.
+
+use aoc_common::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    // Page numbers are always 2 digits
+    let mut pages: Vec<i64> = (10..100).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+
+    // The shuffled order is the correct order
+    let mut lines = Vec::new();
+    for (i, before) in pages.iter().enumerate() {
+        for after in &pages[i + 1..] {
+            lines.push(format!("{before}|{after}"));
+        }
+    }
+    rng.shuffle(&mut lines);
+    lines.push(String::new());
+
+    for _ in 0..size {
+        let mut update = pages.clone();
+        rng.shuffle(&mut update);
+        // Updates always have a middle page
+        update.truncate(rng.below(11) * 2 + 5);
+        if rng.chance(0.5) {
+            update.sort_by_key(|p| pages.iter().position(|q| q == p));
+        }
+        let update: Vec<String> = update.iter().map(|p| p.to_string()).collect();
+        lines.push(update.join(","));
+    }
+    lines
+}
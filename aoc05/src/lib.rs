@@ -0,0 +1,171 @@
+//! Day 5: Print Queue. Checks page updates against ordering rules.
+
+pub mod generate;
+
+use aoc_common::{nth_token, parse_token, trace, Answer, ParseError, Solution};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Precedence {
+    Unknown = 0,
+    Before,
+    After,
+}
+
+// Indexed as [BEFORE * 100 + AFTER]
+type PrecedenceTable = [Precedence; 100 * 100];
+
+/// The page ordering rules and the updates to check against them.
+pub struct PageData {
+    precedence_table: PrecedenceTable,
+    page_list: Vec<Vec<usize>>,
+}
+
+fn read_page(line_idx: usize, line: &str, tok: &str) -> Result<usize, ParseError> {
+    let page = parse_token::<usize>(line_idx, line, tok)?;
+    if page >= 100 {
+        return Err(ParseError::new(
+            line_idx,
+            line,
+            tok,
+            "Page numbers must be below 100",
+        ));
+    }
+    Ok(page)
+}
+
+/// Reads the `X|Y` ordering rules, then a blank line, then the comma separated updates.
+pub fn read_page_info(lines: &[String]) -> Result<PageData, ParseError> {
+    let mut precedence_table: PrecedenceTable = [Precedence::Unknown; 100 * 100];
+    let mut page_list = Vec::new();
+
+    let mut line_iter = lines.iter().enumerate();
+    loop {
+        match line_iter.next() {
+            None => {
+                return Err(ParseError::end_of_input(
+                    lines,
+                    "Missing blank line after the ordering rules",
+                ));
+            }
+            Some((i, line)) => {
+                if line.is_empty() {
+                    break;
+                }
+                let toks = line.split('|').collect::<Vec<_>>();
+                let before = read_page(i, line, nth_token(i, line, &toks, 0)?)?;
+                let after = read_page(i, line, nth_token(i, line, &toks, 1)?)?;
+                precedence_table[before * 100 + after] = Precedence::Before;
+                precedence_table[after * 100 + before] = Precedence::After;
+            }
+        }
+    }
+
+    // Get the remaining lines
+    for (i, line) in line_iter {
+        let mut page_numbers = Vec::new();
+        let toks = line.split(',').collect::<Vec<_>>();
+        for tok in toks {
+            let page = read_page(i, line, tok)?;
+            if page_numbers.contains(&page) {
+                return Err(ParseError::new(i, line, tok, "Duplicate page"));
+            }
+            page_numbers.push(page);
+        }
+        page_list.push(page_numbers);
+    }
+
+    Ok(PageData {
+        precedence_table,
+        page_list,
+    })
+}
+
+fn check_valid(precedence_table: &PrecedenceTable, page_numbers: &[usize]) -> bool {
+    let mut seen_pages = Vec::new();
+    for page in page_numbers {
+        if seen_pages.contains(page) {
+            panic!("Duplicate page {page}");
+        }
+
+        for before in &seen_pages {
+            if precedence_table[before * 100 + page] == Precedence::After {
+                return false;
+            };
+        }
+
+        seen_pages.push(*page);
+    }
+
+    true
+}
+
+/// Sums the middle page of every update that is already in order.
+pub fn calculate_middle_page_sum(page_data: &PageData) -> usize {
+    let mut sum = 0;
+    let page_list = &page_data.page_list;
+    let precedence_table = &page_data.precedence_table;
+    for page_numbers in page_list {
+        if check_valid(precedence_table, page_numbers) {
+            let mid = page_numbers.len() / 2;
+            sum += page_numbers[mid];
+        }
+    }
+
+    sum
+}
+
+/// Puts each update that is out of order into order, and sums their middle pages.
+pub fn calculate_invalid_middle_page_sum(page_data: &PageData) -> usize {
+    let mut sum = 0;
+    let page_list = page_data.page_list.clone();
+    let precedence_table = &page_data.precedence_table;
+    for mut page_numbers in page_list {
+        // Only consider invalid sequences
+        if check_valid(precedence_table, &page_numbers) {
+            continue;
+        }
+        for b in 0..page_numbers.len() {
+            for a in b + 1..page_numbers.len() {
+                let before = page_numbers[b];
+                let after = page_numbers[a];
+                // Simple swap if we're supposed to actually be after
+                if precedence_table[before * 100 + after] == Precedence::After {
+                    page_numbers[b] = after;
+                    page_numbers[a] = before;
+                }
+            }
+        }
+
+        let valid = check_valid(precedence_table, &page_numbers);
+        if valid {
+            trace!("valid: {page_numbers:?}");
+            let mid = page_numbers.len() / 2;
+            sum += page_numbers[mid];
+        } else {
+            panic!("Invalid swapped sequence {:?}", page_numbers);
+        }
+    }
+
+    sum
+}
+
+/// Day 5: Print Queue.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+
+    type Parsed = PageData;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        read_page_info(lines)
+    }
+
+    fn part1(parsed: Self::Parsed) -> Answer {
+        calculate_middle_page_sum(&parsed).into()
+    }
+
+    fn part2(parsed: Self::Parsed) -> Answer {
+        calculate_invalid_middle_page_sum(&parsed).into()
+    }
+}
@@ -0,0 +1,47 @@
+// A random `size` x `size` lab with the guard somewhere inside. The guard has to walk out of the
+// lab eventually, so maps that trap it in a loop are thrown away and tried again.
+
+use aoc_common::{Direction, Rng};
+use std::collections::HashSet;
+
+fn guard_escapes(grid: &[Vec<char>], start: (usize, usize)) -> bool {
+    let size = grid.len();
+    let mut seen = HashSet::new();
+    let (mut pos, mut dir) = (start, Direction::Up);
+    while seen.insert((pos, dir)) {
+        let (dx, dy) = dir.offset();
+        let (Some(x), Some(y)) = (
+            pos.0.checked_add_signed(dx).filter(|&x| x < size),
+            pos.1.checked_add_signed(dy).filter(|&y| y < size),
+        ) else {
+            return true;
+        };
+        if grid[y][x] == '#' {
+            dir = dir.turn_right();
+        } else {
+            pos = (x, y);
+        }
+    }
+    false
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let size = size.max(1);
+    loop {
+        let mut grid: Vec<Vec<char>> = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| if rng.chance(0.05) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        let start = (rng.below(size), rng.below(size));
+        grid[start.1][start.0] = '^';
+        if guard_escapes(&grid, start) {
+            return grid
+                .into_iter()
+                .map(|row| row.into_iter().collect())
+                .collect();
+        }
+    }
+}
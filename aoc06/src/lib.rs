@@ -0,0 +1,365 @@
+//! Day 6: Guard Gallivant. Follows a guard around a lab until they leave it.
+
+pub mod generate;
+
+use aoc_common::{par_map, Animate, Animation, Answer, Color, Direction, ParseError, Solution};
+use std::collections::HashSet;
+use std::fmt;
+
+#[derive(Clone, PartialEq)]
+enum GridPoint {
+    Empty,
+    Obstruction,
+    NewObstruction,
+    Visited,
+    VisitedUpDown,
+    VisitedLeftRight,
+    VisitedUpDownLeftRight,
+    VisitedStartUp,
+}
+
+impl GridPoint {
+    fn symbol(&self) -> char {
+        match self {
+            Self::Empty => '.',
+            Self::Obstruction => '#',
+            Self::NewObstruction => 'O',
+            Self::Visited => 'X',
+            Self::VisitedUpDown => '|',
+            Self::VisitedLeftRight => '-',
+            Self::VisitedUpDownLeftRight => '+',
+            Self::VisitedStartUp => '^',
+        }
+    }
+}
+
+impl fmt::Debug for GridPoint {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}", self.symbol())
+    }
+}
+
+// The guard is drawn as an arrow on top of the path they've marked so far
+const PALETTE: &[(char, Color)] = &[
+    ('#', Color::Dim),
+    ('O', Color::Red),
+    ('X', Color::Green),
+    ('|', Color::Green),
+    ('-', Color::Green),
+    ('+', Color::Green),
+    ('^', Color::Yellow),
+    ('>', Color::Yellow),
+    ('v', Color::Yellow),
+    ('<', Color::Yellow),
+];
+
+// The guard's location along with the direction they are facing
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Cursor {
+    x: usize,
+    y: usize,
+    dir: Direction,
+}
+
+impl Cursor {
+    fn next_step(&self) -> Cursor {
+        let (x, y) = self.dir.step((self.x, self.y));
+        Cursor {
+            x,
+            y,
+            dir: self.dir,
+        }
+    }
+}
+
+/// The lab, along with the path the guard has walked so far.
+#[derive(Clone)]
+pub struct Grid {
+    points: Vec<Vec<GridPoint>>,
+    width: usize,
+    height: usize,
+    start: Cursor,
+    basic: bool,
+}
+
+impl Grid {
+    fn new() -> Self {
+        Self {
+            points: Vec::new(),
+            width: 0,
+            height: 0,
+            start: Cursor {
+                x: 0,
+                y: 0,
+                dir: Direction::Up,
+            },
+            basic: false,
+        }
+    }
+
+    /// Reads the lab, which must have exactly one guard starting out facing up (`^`).
+    pub fn create_grid(lines: &[String]) -> Result<Self, ParseError> {
+        let mut grid = Grid::new();
+        for (y, line) in lines.iter().enumerate() {
+            let mut row: Vec<GridPoint> = Vec::new();
+            for (i, c) in line.char_indices() {
+                let point = match c {
+                    '.' => GridPoint::Empty,
+                    '#' => GridPoint::Obstruction,
+                    '^' => GridPoint::VisitedStartUp,
+                    _ => {
+                        let text = &line[i..i + c.len_utf8()];
+                        return Err(ParseError::new(y, line, text, "Invalid character"));
+                    }
+                };
+                row.push(point);
+            }
+            if !grid.points.is_empty() && row.len() != grid.points[0].len() {
+                return Err(ParseError::new(y, line, line, "Ragged grid line"));
+            }
+            grid.points.push(row);
+        }
+
+        grid.height = grid.points.len();
+        if grid.height > 0 {
+            grid.width = grid.points[0].len();
+        }
+
+        for y in 0..grid.height {
+            for x in 0..grid.width {
+                if grid.points[y][x] == GridPoint::VisitedStartUp {
+                    grid.start.x = x;
+                    grid.start.y = y;
+                    return Ok(grid);
+                }
+            }
+        }
+        Err(ParseError::end_of_input(lines, "Grid has no start"))
+    }
+
+    fn is_on_edge(&self, cursor: &Cursor) -> bool {
+        match cursor.dir {
+            Direction::Up => cursor.y == 0,
+            Direction::Down => cursor.y == self.height - 1,
+            Direction::Left => cursor.x == 0,
+            Direction::Right => cursor.x == self.width - 1,
+        }
+    }
+
+    fn collision(&self, cursor: &Cursor) -> bool {
+        matches!(
+            self.points[cursor.y][cursor.x],
+            GridPoint::Obstruction | GridPoint::NewObstruction
+        )
+    }
+
+    fn mark(&mut self, cursor: &Cursor) {
+        if cursor.x >= self.width || cursor.y >= self.height {
+            panic!("Invalid coord {:?} for grid {:?}", &cursor, &self);
+        }
+        let point = &mut self.points[cursor.y][cursor.x];
+        if self.basic {
+            *point = GridPoint::Visited;
+        } else {
+            *point = match cursor.dir {
+                Direction::Up | Direction::Down => match point {
+                    GridPoint::VisitedLeftRight | GridPoint::VisitedUpDownLeftRight => {
+                        GridPoint::VisitedUpDownLeftRight
+                    }
+                    GridPoint::VisitedStartUp => GridPoint::VisitedStartUp,
+                    _ => GridPoint::VisitedUpDown,
+                },
+                Direction::Left | Direction::Right => match point {
+                    GridPoint::VisitedUpDown | GridPoint::VisitedUpDownLeftRight => {
+                        GridPoint::VisitedUpDownLeftRight
+                    }
+                    GridPoint::VisitedStartUp => GridPoint::VisitedStartUp,
+                    _ => GridPoint::VisitedLeftRight,
+                },
+            }
+        }
+    }
+
+    fn rows(&self, guard: Option<Cursor>) -> impl Iterator<Item = String> + '_ {
+        self.points.iter().enumerate().map(move |(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(x, point)| match guard {
+                    Some(g) if (g.x, g.y) == (x, y) => g.dir.arrow(),
+                    _ => point.symbol(),
+                })
+                .collect::<String>()
+        })
+    }
+
+    /// Walks the guard out of the lab, and returns how many positions they visited. Records a
+    /// frame each time the guard turns, if there's an animation to add to.
+    pub fn guard_visit(&mut self, mut animation: Option<&mut Animation>) -> u32 {
+        let mut cursor = self.start;
+        let mut steps = 0;
+        if let Some(animation) = animation.as_deref_mut() {
+            animation.push("Start", self.rows(Some(cursor)));
+        }
+        while !self.is_on_edge(&cursor) {
+            let next = cursor.next_step();
+            if self.collision(&next) {
+                cursor.dir = cursor.dir.turn_right();
+                if let Some(animation) = animation.as_deref_mut() {
+                    let caption = format!("Turned {:?} after {steps} steps", cursor.dir);
+                    animation.push(caption, self.rows(Some(cursor)));
+                }
+            } else {
+                cursor = next;
+                steps += 1;
+            }
+            self.mark(&cursor);
+        }
+        if let Some(animation) = animation {
+            let caption = format!("Left the map after {steps} steps");
+            animation.push(caption, self.rows(Some(cursor)));
+        }
+
+        let mut visited = 0;
+        for row in &self.points {
+            for col in row {
+                match col {
+                    // Everything that isn't empty or an obstruction is "Visited".
+                    GridPoint::Empty | GridPoint::Obstruction => {}
+                    _ => visited += 1,
+                }
+            }
+        }
+        visited
+    }
+
+    fn check_for_loop(&mut self, new_obstruction: &Cursor) -> bool {
+        let mut visited = HashSet::new();
+        let mut cursor = self.start;
+        self.points[new_obstruction.y][new_obstruction.x] = GridPoint::NewObstruction;
+        while !self.is_on_edge(&cursor) {
+            if visited.contains(&cursor) {
+                return true;
+            } else {
+                visited.insert(cursor);
+            }
+            let next = cursor.next_step();
+            if self.collision(&next) {
+                cursor.dir = cursor.dir.turn_right();
+            } else {
+                cursor = next;
+            }
+            self.mark(&cursor);
+        }
+
+        false
+    }
+
+    /// Counts the positions where a single new obstruction traps the guard in a loop. Each loop
+    /// that's found is recorded as a frame, if there's an animation to add to.
+    //
+    // Since we are only placing 1 new obstruction, the only valid locations for placement would
+    // be coordinates that we visit on the usual guard path. Thus we already eliminate a large
+    // number of locations that we would need to try. Each location is only tried once, the
+    // first time the guard reaches it, since there are occasional redundant overlaps that we
+    // could skip. Every try is independent, so they can run in parallel.
+    pub fn compute_possible_obstructions(&self, animation: Option<&mut Animation>) -> usize {
+        let mut candidates = Vec::new();
+        let mut tried = HashSet::new();
+        let mut cursor = self.start;
+        while !self.is_on_edge(&cursor) {
+            let next = cursor.next_step();
+            if self.collision(&next) {
+                cursor.dir = cursor.dir.turn_right();
+            } else {
+                // Need to ignore direction on the obstruction, since we only count unique locations
+                if tried.insert((next.x, next.y)) {
+                    candidates.push(next);
+                }
+                cursor = next;
+            }
+        }
+
+        let loops = par_map(&candidates, |next| self.clone().check_for_loop(next));
+        let obstructions: Vec<&Cursor> = candidates
+            .iter()
+            .zip(loops)
+            .filter_map(|(next, looped)| looped.then_some(next))
+            .collect();
+
+        if let Some(animation) = animation {
+            // Each loop is walked again to draw it, which only animations need
+            for (i, next) in obstructions.iter().enumerate() {
+                let mut new_grid = self.clone();
+                new_grid.check_for_loop(next);
+                let caption = format!(
+                    "Loop {} with an obstruction at ({}, {})",
+                    i + 1,
+                    next.x,
+                    next.y
+                );
+                animation.push(caption, new_grid.rows(None));
+            }
+            let mut all = self.clone();
+            for next in &obstructions {
+                all.points[next.y][next.x] = GridPoint::NewObstruction;
+            }
+            let caption = format!("{} obstructions make a loop", obstructions.len());
+            animation.push(caption, all.rows(None));
+        }
+        obstructions.len()
+    }
+}
+
+impl fmt::Debug for Grid {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(fmt)?;
+        writeln!(fmt, "width: {}", self.width)?;
+        writeln!(fmt, "height: {}", self.height)?;
+        writeln!(fmt, "start: {:?}", self.start)?;
+        writeln!(fmt, "basic: {}", self.basic)?;
+        for row in self.rows(None) {
+            writeln!(fmt, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Day 6: Guard Gallivant.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+
+    type Parsed = Grid;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        Grid::create_grid(lines)
+    }
+
+    fn part1(mut parsed: Self::Parsed) -> Answer {
+        parsed.guard_visit(None).into()
+    }
+
+    fn part2(parsed: Self::Parsed) -> Answer {
+        parsed.compute_possible_obstructions(None).into()
+    }
+}
+
+impl Animate for Day06 {
+    fn animate(mut parsed: Self::Parsed, part: u32) -> Animation {
+        let mut animation = Animation::new(PALETTE);
+        if part == 1 {
+            parsed.guard_visit(Some(&mut animation));
+        } else {
+            parsed.compute_possible_obstructions(Some(&mut animation));
+        }
+        animation
+    }
+}
+
+#[test]
+fn test_invalid_char() {
+    let lines = vec!["..#.".to_string(), ".^x.".to_string()];
+    let err = Grid::create_grid(&lines).unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "x"));
+}
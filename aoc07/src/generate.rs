@@ -0,0 +1,32 @@
+// Random calibration equations: `size` lines of up to 12 small numbers. Most are built with
+// real operators so they can be solved, and the rest are nudged so that they can't be (usually).
+
+use aoc_common::Rng;
+
+// Leave plenty of headroom under u64, even with concatenation
+const MAX_TARGET: u64 = 1 << 50;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut lines = Vec::with_capacity(size);
+    while lines.len() < size {
+        let count = rng.below(11) + 2;
+        let nums: Vec<u64> = (0..count).map(|_| rng.range(1..1000) as u64).collect();
+        let mut target = Some(nums[0]);
+        for &n in &nums[1..] {
+            target = target.and_then(|t| match rng.below(3) {
+                0 => t.checked_add(n),
+                1 => t.checked_mul(n),
+                _ => format!("{t}{n}").parse().ok(),
+            });
+        }
+        let Some(mut target) = target.filter(|&t| t < MAX_TARGET) else {
+            continue;
+        };
+        if rng.chance(0.3) {
+            target += 1;
+        }
+        let nums: Vec<String> = nums.iter().map(|n| n.to_string()).collect();
+        lines.push(format!("{target}: {}", nums.join(" ")));
+    }
+    lines
+}
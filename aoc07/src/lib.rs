@@ -0,0 +1,190 @@
+//! Day 7: Bridge Repair. Finds the operators that make each calibration equation true.
+
+pub mod generate;
+
+use aoc_common::{par_map, parse_token, Answer, ParseError, Solution};
+
+#[derive(PartialEq)]
+enum Operation {
+    Add,
+    Mul,
+    Cat,
+}
+
+// The puzzle's test values fit in 64 bits, and the `checked` feature allows for scaled-up ones
+// that need 128. Either way, the operators are checked, since an intermediate value that
+// overflows is past any test value we could have parsed.
+#[cfg(feature = "checked")]
+pub type Value = u128;
+#[cfg(not(feature = "checked"))]
+pub type Value = usize;
+
+/// A calibration equation's test value, and the operands that should produce it.
+pub struct Equation {
+    result: Value,
+    operands: Vec<Value>,
+}
+
+/// Reads lines like `190: 10 19`.
+pub fn read_equations(lines: &[String]) -> Result<Vec<Equation>, ParseError> {
+    let mut equations = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let (lhs, rhs) = line
+            .split_once(": ")
+            .ok_or_else(|| ParseError::new(i, line, line, "Missing `: ` separator"))?;
+        let result = parse_token(i, line, lhs)?;
+        let operands = rhs
+            .split_ascii_whitespace()
+            .map(|o| parse_token(i, line, o))
+            .collect::<Result<Vec<Value>, _>>()?;
+        if operands.is_empty() {
+            return Err(ParseError::new(i, line, rhs, "Missing operands"));
+        }
+        let eq = Equation { result, operands };
+        equations.push(eq);
+    }
+
+    Ok(equations)
+}
+
+// target - the amount we want to compute
+// result - our current running total
+// operands - the immutable list of operands
+// operation_index - where we currently are working
+// operation - the add/mul we should apply
+fn try_operation(
+    target: Value,
+    result: Value,
+    operands: &Vec<Value>,
+    operation_index: usize,
+    operation: Operation,
+    cat_supported: bool,
+) -> bool {
+    if result > target || (!cat_supported && operation == Operation::Cat) {
+        return false;
+    }
+
+    // Perform the operation
+    let rhs = operands[operation_index + 1];
+    let new_result = match operation {
+        Operation::Add => result.checked_add(rhs),
+        Operation::Mul => result.checked_mul(rhs),
+        Operation::Cat => {
+            // Just convert with a format string and count the digits to multiply by 10
+            let num_digits = format!("{}", rhs);
+            (0..num_digits.len())
+                .try_fold(result, |result, _| result.checked_mul(10))
+                .and_then(|result| result.checked_add(rhs))
+        }
+    };
+    let Some(new_result) = new_result else {
+        return false;
+    };
+
+    if operation_index + 2 == operands.len() {
+        return new_result == target;
+    } else {
+        // Recurse by checking add and/or multiply, but early exit if we're already over
+        if new_result > target {
+            return false;
+        }
+
+        // Try add and then try multiply recursively
+        if try_operation(
+            target,
+            new_result,
+            operands,
+            operation_index + 1,
+            Operation::Add,
+            cat_supported,
+        ) {
+            return true;
+        }
+        if try_operation(
+            target,
+            new_result,
+            operands,
+            operation_index + 1,
+            Operation::Mul,
+            cat_supported,
+        ) {
+            return true;
+        }
+        if cat_supported
+            && try_operation(
+                target,
+                new_result,
+                operands,
+                operation_index + 1,
+                Operation::Cat,
+                cat_supported,
+            )
+        {
+            return true;
+        }
+    }
+    false
+}
+
+/// Whether some choice of `+` and `*` (and `||` when `cat_supported` is set) between the
+/// operands, evaluated left to right, produces the test value.
+pub fn calibrate(equation: &Equation, cat_supported: bool) -> bool {
+    let target = equation.result;
+    let operands = &equation.operands;
+    let result = operands[0];
+    if try_operation(target, result, operands, 0, Operation::Add, cat_supported) {
+        return true;
+    }
+    if try_operation(target, result, operands, 0, Operation::Mul, cat_supported) {
+        return true;
+    }
+    if cat_supported && try_operation(target, result, operands, 0, Operation::Cat, cat_supported) {
+        return true;
+    }
+    false
+}
+
+/// Sums the test values of the equations that `calibrate` can make true, unless the sum
+/// overflows.
+pub fn calibrate_all(equations: &[Equation], cat_supported: bool) -> Result<Value, String> {
+    // Every equation is independent, so they can be checked in parallel
+    let valid = par_map(equations, |equation| calibrate(equation, cat_supported));
+    let mut sum = 0;
+    for (equation, valid) in equations.iter().zip(valid) {
+        if valid {
+            sum = Value::checked_add(sum, equation.result)
+                .ok_or("Total overflows (the `checked` feature makes it 128 bits)")?;
+        }
+    }
+
+    Ok(sum)
+}
+
+/// Day 7: Bridge Repair.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+
+    type Parsed = Vec<Equation>;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        read_equations(lines)
+    }
+
+    fn part1(parsed: Self::Parsed) -> Answer {
+        calibrate_all(&parsed, false).into()
+    }
+
+    fn part2(parsed: Self::Parsed) -> Answer {
+        calibrate_all(&parsed, true).into()
+    }
+}
+
+#[test]
+fn test_total_overflows() {
+    let line = format!("{}: {} 1", Value::MAX, Value::MAX);
+    let equations = read_equations(&[line.clone(), line]).unwrap();
+    assert!(calibrate_all(&equations[..1], false).is_ok());
+    assert!(calibrate_all(&equations, false).is_err());
+}
@@ -0,0 +1,20 @@
+// A random `size` x `size` antenna map. Each frequency gets a handful of antennas, and there are
+// about as many frequencies as the real input has for its size.
+
+use aoc_common::Rng;
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let size = size.max(1);
+    let mut grid = vec![vec!['.'; size]; size];
+    let frequencies = (size / 2).clamp(1, FREQUENCIES.len());
+    for &freq in &FREQUENCIES[..frequencies] {
+        for _ in 0..rng.below(3) + 2 {
+            grid[rng.below(size)][rng.below(size)] = freq as char;
+        }
+    }
+    grid.into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect()
+}
@@ -0,0 +1,141 @@
+//! Day 8: Resonant Collinearity. Counts the antinodes made by pairs of antennas.
+
+pub mod generate;
+
+use aoc_common::{debug, trace, Answer, ParseError, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+type Coord = (isize, isize);
+
+/// The antenna map, with each antenna's location grouped by its frequency.
+pub struct Graph {
+    antennas: HashMap<char, Vec<Coord>>,
+    width: usize,
+    height: usize,
+}
+
+impl Graph {
+    fn inbounds(&self, coord: Coord) -> bool {
+        coord.0 >= 0
+            && coord.0 < self.width as isize
+            && coord.1 >= 0
+            && coord.1 < self.height as isize
+    }
+
+    fn compute_antinodes(&self, left: Coord, right: Coord, harmonics: bool) -> Vec<Coord> {
+        let mut nodes = Vec::new();
+        let mut coord = left;
+        let diff = (left.0 - right.0, left.1 - right.1);
+        if harmonics {
+            while self.inbounds(coord) {
+                nodes.push(coord);
+                coord = (coord.0 + diff.0, coord.1 + diff.1);
+            }
+        } else {
+            coord = (coord.0 + diff.0, coord.1 + diff.1);
+            if self.inbounds(coord) {
+                nodes.push(coord);
+            }
+        }
+
+        nodes
+    }
+
+    /// Counts the unique locations of antinodes on the map. With `harmonics`, every position in
+    /// line with a pair of antennas counts, not just those twice as far from one as the other.
+    pub fn find_antinodes(&mut self, harmonics: bool) -> usize {
+        let mut antinodes = HashSet::new();
+        for locs in self.antennas.values() {
+            for (idx, &left) in locs.iter().enumerate() {
+                for &right in locs.iter().skip(idx + 1) {
+                    let nodes = self.compute_antinodes(left, right, harmonics);
+                    for node in nodes {
+                        antinodes.insert(node);
+                    }
+                    let nodes = self.compute_antinodes(right, left, harmonics);
+                    for node in nodes {
+                        antinodes.insert(node);
+                    }
+                }
+            }
+        }
+
+        debug!("Antinodes:{}", self.antinode_map(&antinodes));
+        antinodes.len()
+    }
+
+    fn antinode_map(&self, antinodes: &HashSet<Coord>) -> String {
+        let mut map = String::new();
+        for y in 0..self.height as isize {
+            map.push('\n');
+            for x in 0..self.width as isize {
+                let coord = (x, y);
+                if antinodes.contains(&coord) {
+                    map.push('#');
+                } else {
+                    map.push('.');
+                }
+            }
+        }
+        map
+    }
+}
+
+/// Reads the antenna map, where `.` is empty and any other character is an antenna.
+pub fn read_graph(lines: &[String]) -> Result<Graph, ParseError> {
+    let mut antennas: HashMap<char, Vec<Coord>> = HashMap::new();
+    let height = lines.len();
+    let width = match lines.first() {
+        Some(line) => line.len(),
+        None => return Err(ParseError::end_of_input(lines, "Empty map")),
+    };
+
+    for (y, line) in lines.iter().enumerate() {
+        if line.len() != width {
+            return Err(ParseError::new(y, line, line, "Ragged map line"));
+        }
+        for (x, c) in line.char_indices() {
+            if !c.is_ascii_alphanumeric() && c != '.' {
+                let text = &line[x..x + c.len_utf8()];
+                return Err(ParseError::new(y, line, text, "Invalid antenna"));
+            }
+            if c != '.' {
+                let coord = (x as isize, y as isize);
+                if let std::collections::hash_map::Entry::Vacant(e) = antennas.entry(c) {
+                    e.insert(vec![coord]);
+                } else {
+                    antennas.get_mut(&c).unwrap().push(coord);
+                }
+            }
+        }
+    }
+
+    trace!("{antennas:?}");
+    Ok(Graph {
+        antennas,
+        width,
+        height,
+    })
+}
+
+/// Day 8: Resonant Collinearity.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+
+    type Parsed = Graph;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        read_graph(lines)
+    }
+
+    fn part1(mut parsed: Self::Parsed) -> Answer {
+        parsed.find_antinodes(false).into()
+    }
+
+    fn part2(mut parsed: Self::Parsed) -> Answer {
+        parsed.find_antinodes(true).into()
+    }
+}
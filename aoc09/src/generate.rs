@@ -0,0 +1,20 @@
+// A random disk map `size` digits long, alternating between files and free space and ending with
+// a file. Files always take up at least one block.
+
+use aoc_common::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    // An odd length ends on a file, just like the real input
+    let size = size.max(1) | 1;
+    let map: String = (0..size)
+        .map(|i| {
+            let digit = if i % 2 == 0 {
+                rng.range(1..10)
+            } else {
+                rng.range(0..10)
+            };
+            char::from(b'0' + digit as u8)
+        })
+        .collect();
+    vec![map]
+}
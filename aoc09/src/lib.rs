@@ -0,0 +1,290 @@
+//! Day 9: Disk Fragmenter. Compacts the files on a disk and checksums the result.
+
+pub mod generate;
+
+#[cfg(test)]
+use aoc_common::{check, shrink_vec};
+use aoc_common::{trace, Answer, ParseError, Solution};
+
+fn filesystem_string(filesystem: &[isize]) -> String {
+    let mut text = String::new();
+    for &id in filesystem {
+        if id == -1 {
+            text.push('.');
+        } else {
+            text.push_str(&id.to_string());
+        }
+    }
+    text
+}
+
+fn blocks_string(blocks: &[Block]) -> String {
+    let mut text = String::new();
+    for &block in blocks {
+        match block.block_type {
+            BlockType::Space => {
+                (0..block.size).for_each(|_| text.push('.'));
+            }
+            BlockType::File => {
+                (0..block.size).for_each(|_| text.push_str(&block.file_id.to_string()));
+            }
+        }
+    }
+    text
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BlockType {
+    File,
+    Space,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Block {
+    block_type: BlockType,
+    file_id: isize,
+    size: usize,
+}
+
+/// A disk map, alternating between the length of a file and the free space after it.
+#[derive(Debug)]
+pub struct Disk {
+    diskmap: Vec<usize>,
+}
+
+impl Disk {
+    fn create_filesystem(&self) -> Vec<isize> {
+        let mut file_id: isize = 0;
+        let mut filesystem = Vec::new();
+        for (idx, &v) in self.diskmap.iter().enumerate() {
+            if idx % 2 == 0 {
+                for _ in 0..v {
+                    filesystem.push(file_id);
+                }
+                file_id += 1;
+            } else {
+                for _ in 0..v {
+                    filesystem.push(-1);
+                }
+            }
+        }
+
+        filesystem
+    }
+
+    fn create_blocks(&self) -> Vec<Block> {
+        let mut file_id: isize = 0;
+        let mut blocks = Vec::new();
+        for (idx, &size) in self.diskmap.iter().enumerate() {
+            if idx % 2 == 0 {
+                let block_type = BlockType::File;
+                let block = Block {
+                    block_type,
+                    file_id,
+                    size,
+                };
+                blocks.push(block);
+                file_id += 1;
+            } else if size > 0 {
+                let block_type = BlockType::Space;
+                let block = Block {
+                    block_type,
+                    file_id: -1,
+                    size,
+                };
+                blocks.push(block);
+            }
+        }
+
+        blocks
+    }
+
+    fn compact_filesystem(&self, filesystem: &mut [isize]) {
+        let total_blocks = filesystem.iter().filter(|x| !x.is_negative()).count();
+
+        let mut i = 0;
+        let mut last = filesystem.len() - 1;
+        while i < total_blocks {
+            // Find the first empty block, if there's any left before the end of the files
+            while i < total_blocks && filesystem[i] != -1 {
+                i += 1;
+            }
+
+            // Find the previous file block
+            while filesystem[last] == -1 {
+                last -= 1;
+            }
+
+            if i >= total_blocks {
+                break;
+            }
+
+            filesystem[i] = filesystem[last];
+            filesystem[last] = -1;
+            i += 1;
+            last -= 1;
+        }
+    }
+
+    // These always move blocks from back to front, so we don't need to worry about coalescing free space at the back.
+    fn move_block(blocks: &mut Vec<Block>, file_id: isize) {
+        let file_block_id = blocks
+            .iter()
+            .enumerate()
+            .find(|(_, x)| x.file_id == file_id)
+            .unwrap()
+            .0;
+        let size = blocks[file_block_id].size;
+        if let Some((space_block_id, space_block)) = blocks.iter().enumerate().find(|(bidx, x)| {
+            bidx < &file_block_id && x.block_type == BlockType::Space && x.size >= size
+        }) {
+            let leftover_space = space_block.size - size;
+            if leftover_space == 0 {
+                // We can just swap in the data here in this case! :)
+                blocks[space_block_id].block_type = BlockType::File;
+                blocks[space_block_id].file_id = file_id;
+                blocks[file_block_id].block_type = BlockType::Space;
+                blocks[file_block_id].file_id = -1;
+            } else {
+                let new_block = blocks[file_block_id];
+                // Subtract off the space for the newly moved file
+                blocks[space_block_id].size -= size;
+                blocks[file_block_id].block_type = BlockType::Space;
+                blocks[file_block_id].file_id = -1;
+                // Insert the block last, since we otherwise run into trouble with indices
+                blocks.insert(space_block_id, new_block);
+            }
+        }
+    }
+
+    /// The checksum after moving whole files, highest ID first, into the leftmost free space
+    /// that fits them.
+    pub fn compute_checksum_whole(&self) -> usize {
+        let mut blocks = self.create_blocks();
+
+        // Find the highest numbered File block
+        let last_block_id = blocks
+            .iter()
+            .rev()
+            .find(|x| x.block_type == BlockType::File)
+            .unwrap()
+            .file_id;
+
+        // Work backwards through the file_ids
+        for file_id in (1..=last_block_id).rev() {
+            Self::move_block(&mut blocks, file_id);
+        }
+
+        trace!("{}", blocks_string(&blocks));
+
+        // Compute the actual checksum
+        let mut checksum = 0;
+        let mut idx = 0;
+        for block in blocks {
+            match block.block_type {
+                BlockType::Space => {
+                    idx += block.size;
+                }
+                BlockType::File => {
+                    for _ in 0..block.size {
+                        checksum += idx * block.file_id as usize;
+                        idx += 1;
+                    }
+                }
+            }
+        }
+        checksum
+    }
+
+    /// The checksum after moving blocks one at a time from the end of the disk into the leftmost
+    /// free block.
+    pub fn compute_checksum(&self) -> usize {
+        let mut filesystem = self.create_filesystem();
+        self.compact_filesystem(&mut filesystem);
+        trace!("{}", filesystem_string(&filesystem));
+
+        let checksum = filesystem
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| !x.is_negative())
+            .fold(0, |sum, (idx, &v)| sum + idx * v as usize);
+
+        checksum
+    }
+}
+
+/// Reads the disk map, a single line of digits.
+pub fn read_diskmap(lines: &[String]) -> Result<Disk, ParseError> {
+    let line = match lines {
+        [line] => line,
+        [] => return Err(ParseError::end_of_input(lines, "Missing disk map")),
+        [_, extra, ..] => return Err(ParseError::new(1, extra, extra, "Expected a single line")),
+    };
+    let mut diskmap: Vec<usize> = Vec::new();
+    for (i, c) in line.char_indices() {
+        let val = match c.to_digit(10) {
+            Some(val) => val as usize,
+            None => {
+                let text = &line[i..i + c.len_utf8()];
+                return Err(ParseError::new(0, line, text, "Invalid char input"));
+            }
+        };
+        diskmap.push(val);
+    }
+
+    Ok(Disk { diskmap })
+}
+
+/// Day 9: Disk Fragmenter.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+
+    type Parsed = Disk;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        read_diskmap(lines)
+    }
+
+    fn part1(parsed: Self::Parsed) -> Answer {
+        parsed.compute_checksum().into()
+    }
+
+    fn part2(parsed: Self::Parsed) -> Answer {
+        parsed.compute_checksum_whole().into()
+    }
+}
+
+#[test]
+fn test_invalid_digit() {
+    let err = read_diskmap(&["2333a33".to_string()]).unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (1, 5, "a"));
+}
+
+#[test]
+fn test_compaction_random() {
+    // The two compactions only agree when every file is a single block, since then moving whole
+    // files and moving blocks one at a time make the same moves
+    check(
+        500,
+        |rng| {
+            let mut diskmap = vec![1];
+            for _ in 0..rng.below(30) {
+                diskmap.extend([rng.below(10), 1]);
+            }
+            Disk { diskmap }
+        },
+        |disk| {
+            // Remove whole space and file pairs so that the map keeps its shape
+            let pairs: Vec<_> = disk.diskmap[1..].chunks(2).collect();
+            shrink_vec(&pairs)
+                .into_iter()
+                .map(|pairs| Disk {
+                    diskmap: [&[1], pairs.concat().as_slice()].concat(),
+                })
+                .collect()
+        },
+        |disk| disk.compute_checksum() == disk.compute_checksum_whole(),
+    );
+}
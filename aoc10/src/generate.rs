@@ -0,0 +1,29 @@
+// A random `size` x `size` topographic map. Random heights almost never form a trail, so some
+// trails are walked into the map as well, each climbing one step at a time from 0 up to 9.
+
+use aoc_common::{Direction, Rng};
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let size = size.max(1);
+    let mut grid: Vec<Vec<u8>> = (0..size)
+        .map(|_| (0..size).map(|_| rng.below(10) as u8).collect())
+        .collect();
+
+    for _ in 0..size * size / 20 {
+        let mut pos = (rng.below(size), rng.below(size));
+        for height in 0..10 {
+            grid[pos.1][pos.0] = height;
+            let (dx, dy) = rng.choose(&Direction::ALL).offset();
+            match (
+                pos.0.checked_add_signed(dx).filter(|&x| x < size),
+                pos.1.checked_add_signed(dy).filter(|&y| y < size),
+            ) {
+                (Some(x), Some(y)) => pos = (x, y),
+                _ => break,
+            }
+        }
+    }
+    grid.into_iter()
+        .map(|row| row.iter().map(|&h| char::from(b'0' + h)).collect())
+        .collect()
+}
@@ -0,0 +1,128 @@
+//! Day 10: Hoof It. Scores the hiking trails on a topographic map.
+
+pub mod generate;
+
+use aoc_common::{debug, trace, Answer, Coord, Grid, ParseError, Solution};
+use std::collections::HashSet;
+
+/// A topographic map of heights from 0 to 9.
+pub struct TopoMap {
+    grid: Grid<i8>,
+}
+
+impl TopoMap {
+    /// Reads the map, where every character must be a digit.
+    pub fn new(lines: &[String]) -> Result<Self, ParseError> {
+        let grid = Grid::from_lines(lines, None, |c| c.to_digit(10).map(|d| d as i8))?;
+        debug!("{grid:?}");
+        Ok(Self { grid })
+    }
+
+    fn get_height(&self, loc: Coord) -> i8 {
+        self.grid[loc]
+    }
+
+    fn find_unique_paths(&self, loc: Coord, prev_height: i8) -> usize {
+        let height = self.get_height(loc);
+
+        // Check that we're going up incrementally!
+        if height != prev_height + 1 {
+            return 0;
+        }
+
+        if height == 9 {
+            // We've reached a plateau
+            trace!("Found plateau {loc:?}");
+            return 1;
+        }
+
+        let mut score = 0;
+        for next in self.grid.neighbors4(loc) {
+            score += self.find_unique_paths(next, height);
+        }
+
+        trace!("intermediate {loc:?} - score {score}");
+        score
+    }
+
+    fn get_trailhead_rating(&self, loc: Coord) -> usize {
+        if self.get_height(loc) == 0 {
+            self.find_unique_paths(loc, -1)
+        } else {
+            0
+        }
+    }
+
+    /// Sums the ratings of every trailhead, which is how many distinct trails lead from it to a 9.
+    pub fn total_rating(&self) -> usize {
+        let mut score = 0;
+        for loc in self.grid.positions() {
+            score += self.get_trailhead_rating(loc);
+        }
+
+        score
+    }
+
+    fn find_unique_plateaus(&self, loc: Coord, prev_height: i8) -> HashSet<Coord> {
+        let height = self.get_height(loc);
+
+        // Check that we're going up incrementally!
+        if height != prev_height + 1 {
+            return HashSet::new();
+        }
+
+        if height == 9 {
+            // We've reached a plateau
+            let mut set = HashSet::new();
+            set.insert(loc);
+            return set;
+        }
+
+        let mut set = HashSet::new();
+        for next in self.grid.neighbors4(loc) {
+            set.extend(self.find_unique_plateaus(next, height));
+        }
+
+        set
+    }
+
+    fn get_trailhead_score(&self, loc: Coord) -> usize {
+        if self.get_height(loc) == 0 {
+            let set = self.find_unique_plateaus(loc, -1);
+            set.len()
+        } else {
+            0
+        }
+    }
+
+    /// Sums the scores of every trailhead, which is how many 9s it can reach by trails.
+    pub fn total_score(&self) -> usize {
+        let mut score = 0;
+        for loc in self.grid.positions() {
+            score += self.get_trailhead_score(loc);
+        }
+
+        score
+    }
+}
+
+/// Day 10: Hoof It.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Parsed = TopoMap;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        TopoMap::new(lines)
+    }
+
+    fn part1(parsed: Self::Parsed) -> Answer {
+        parsed.total_score().into()
+    }
+
+    fn part2(parsed: Self::Parsed) -> Answer {
+        parsed.total_rating().into()
+    }
+}
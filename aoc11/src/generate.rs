@@ -0,0 +1,10 @@
+// A random line of `size` stones, with engravings anywhere from 0 up to 6 digits.
+
+use aoc_common::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let stones: Vec<String> = (0..size.max(1))
+        .map(|_| rng.range(0..1_000_000).to_string())
+        .collect();
+    vec![stones.join(" ")]
+}
@@ -0,0 +1,125 @@
+//! Day 11: Plutonian Pebbles. Counts stones that split and change every blink.
+
+pub mod generate;
+
+#[cfg(test)]
+use aoc_common::fixture;
+use aoc_common::{parse_token, Answer, ParseError, Solution};
+use std::collections::HashMap;
+
+// Stones and counts are 64 bits for the puzzle, and the `checked` feature makes them 128 for
+// scaled-up inputs. A stone with an odd number of digits is multiplied by 2024 at most twice
+// before it has an even number and splits, so any stone up to MAX_STONE can be blinked forever.
+#[cfg(feature = "checked")]
+pub type Value = u128;
+#[cfg(not(feature = "checked"))]
+pub type Value = usize;
+
+const MAX_STONE: Value = Value::MAX / (2024 * 2024);
+
+/// The row of engraved stones.
+pub struct Pluto {
+    stones: Vec<Value>,
+}
+
+impl Pluto {
+    /// Reads the stones, which are all on a single line.
+    pub fn new(lines: &[String]) -> Result<Self, ParseError> {
+        let mut stones = Vec::new();
+        let line = match lines {
+            [line] => line,
+            [] => return Err(ParseError::end_of_input(lines, "Missing stones")),
+            [_, extra, ..] => {
+                return Err(ParseError::new(1, extra, extra, "Expected a single line"))
+            }
+        };
+        let toks = line.split_ascii_whitespace().collect::<Vec<_>>();
+        for tok in toks {
+            let val = parse_token(0, line, tok)?;
+            if val > MAX_STONE {
+                return Err(ParseError::new(0, line, tok, "Stone is too large"));
+            }
+            stones.push(val);
+        }
+
+        Ok(Self { stones })
+    }
+
+    // The count can double with every blink, so enough of them overflow any number of bits, and
+    // then this gives None
+    fn blink_r(stone: Value, n: usize, memo: &mut HashMap<(Value, usize), Value>) -> Option<Value> {
+        if let Some(&total) = memo.get(&(stone, n)) {
+            return Some(total);
+        }
+
+        let total = if n == 0 {
+            // Recursive base case just returns 1 for the stone that we are
+            1
+        } else if stone == 0 {
+            // Rule 1: Replace 0 with 1
+            Self::blink_r(1, n - 1, memo)?
+        } else if format!("{stone}").len() % 2 == 0 {
+            // Rule 2: Split even number of digits into top and bottom halves
+            let stone_str = format!("{stone}");
+            let top = stone_str[..stone_str.len() / 2].parse::<Value>().unwrap();
+            let bot = stone_str[stone_str.len() / 2..].parse::<Value>().unwrap();
+            Self::blink_r(top, n - 1, memo)?.checked_add(Self::blink_r(bot, n - 1, memo)?)?
+        } else {
+            // Rule 3: Replace anything else with the number multiplied by 2024
+            Self::blink_r(stone * 2024, n - 1, memo)?
+        };
+        memo.insert((stone, n), total);
+
+        Some(total)
+    }
+
+    /// Returns the number of stones after blinking `n` times, unless there are too many to count.
+    pub fn blink(&self, n: usize) -> Result<Value, String> {
+        let mut total: Value = 0;
+        let mut memo = HashMap::new();
+
+        for stone in &self.stones {
+            total = Self::blink_r(*stone, n, &mut memo)
+                .and_then(|count| total.checked_add(count))
+                .ok_or("Stone count overflows (the `checked` feature makes it 128 bits)")?;
+        }
+
+        Ok(total)
+    }
+}
+
+/// Day 11: Plutonian Pebbles.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    type Parsed = Pluto;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        Pluto::new(lines)
+    }
+
+    fn part1(parsed: Self::Parsed) -> Answer {
+        parsed.blink(25).into()
+    }
+
+    fn part2(parsed: Self::Parsed) -> Answer {
+        parsed.blink(75).into()
+    }
+}
+
+#[test]
+fn test_blink() {
+    let pluto = Pluto::new(&fixture!("prelim.txt")).unwrap();
+    assert_eq!(pluto.blink(6), Ok(22));
+    // Even 128 bits run out long before this many blinks
+    assert!(pluto.blink(500).is_err());
+}
+
+#[test]
+fn test_stone_too_large() {
+    let lines = [format!("125 {}", MAX_STONE + 1)];
+    let err = Pluto::new(&lines).err().unwrap();
+    assert_eq!((err.column, err.text), (5, (MAX_STONE + 1).to_string()));
+}
@@ -0,0 +1,21 @@
+// A random `size` x `size` garden. Most plots copy the plant above or to the left of them, which
+// grows irregular regions (with the odd hole) instead of single plot noise.
+
+use aoc_common::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let size = size.max(1);
+    let mut grid = vec![vec!['A'; size]; size];
+    for y in 0..size {
+        for x in 0..size {
+            grid[y][x] = match rng.below(10) {
+                0..=3 if x > 0 => grid[y][x - 1],
+                4..=7 if y > 0 => grid[y - 1][x],
+                _ => char::from(b'A' + rng.below(26) as u8),
+            };
+        }
+    }
+    grid.into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect()
+}
@@ -0,0 +1,218 @@
+//! Day 12: Garden Groups. Prices the fences around each region of a garden.
+
+pub mod generate;
+
+use aoc_common::{
+    debug, trace, Answer, Coord, Direction, Grid, Input, ParseError, Solution, INVALID,
+};
+use std::collections::HashSet;
+
+/// The garden plots, where touching plots of the same plant form a region.
+#[derive(Debug)]
+pub struct Garden {
+    grid: Grid,
+    width: usize,
+    height: usize,
+}
+
+impl Garden {
+    /// Reads the garden, which must be rectangular.
+    pub fn new(input: &Input) -> Result<Self, ParseError> {
+        // The border keeps every plot's neighbors (even diagonals) in bounds. Plants are all
+        // letters, so each byte is one plot, and nothing else can be mistaken for the border.
+        let grid = Grid::from_bytes(&input.lines(), Some(INVALID), |b| {
+            b.is_ascii_alphabetic().then_some(char::from(b))
+        })?;
+        let width = grid.width() - 2;
+        let height = grid.height() - 2;
+        debug!("{grid:?}");
+        Ok(Self {
+            grid,
+            width,
+            height,
+        })
+    }
+
+    fn get_region(&self, pos: Coord) -> HashSet<Coord> {
+        let mut region = HashSet::new();
+        let search = self.grid[pos];
+
+        // Let's use an iterative method this time, since we almost always use recursion.
+        let mut candidates = Vec::new();
+        candidates.push(pos);
+        while let Some(pos) = candidates.pop() {
+            if region.contains(&pos) {
+                // We've already seen this plot
+                continue;
+            }
+            if self.grid[pos] != search {
+                // Skip plants that we aren't searching for
+                continue;
+            }
+
+            region.insert(pos);
+            for dir in Direction::ALL {
+                candidates.push(dir.step(pos));
+            }
+        }
+
+        region
+    }
+
+    fn get_area(&self, region: &HashSet<Coord>) -> usize {
+        region.len()
+    }
+
+    fn get_perimeter(&self, region: &HashSet<Coord>) -> usize {
+        let mut perimeter = 0;
+        let (x, y) = region.iter().collect::<Vec<_>>().first().unwrap();
+        let search = self.grid[*y][*x];
+
+        for &pos in region {
+            perimeter += 4;
+            for dir in Direction::ALL {
+                let (x, y) = dir.step(pos);
+                if self.grid[y][x] == search {
+                    perimeter -= 1;
+                }
+            }
+        }
+
+        perimeter
+    }
+
+    fn get_sides(&self, region: &HashSet<Coord>) -> usize {
+        // This comes down to looking for corners, which are equal to the number of sides
+        let mut sides = 0;
+        let (x, y) = region.iter().collect::<Vec<_>>().first().unwrap();
+        let search = self.grid[*y][*x];
+
+        for &pos in region {
+            let (x, y) = pos;
+
+            // Corners can be detected with the following kinds of patterns. For a plot with
+            // plant type O, we look for differing X plants in cardinal directions.
+            //
+            // .X.  .X.  ...  ...
+            // XO.  .OX  XO.  .OX
+            // ...  ...  .X.  .X.
+            let diff_up_left = self.grid[y - 1][x - 1] != search;
+            let diff_up = self.grid[y - 1][x] != search;
+            let diff_up_right = self.grid[y - 1][x + 1] != search;
+            let diff_left = self.grid[y][x - 1] != search;
+            let diff_right = self.grid[y][x + 1] != search;
+            let diff_down_left = self.grid[y + 1][x - 1] != search;
+            let diff_down = self.grid[y + 1][x] != search;
+            let diff_down_right = self.grid[y + 1][x + 1] != search;
+
+            if diff_up && diff_left {
+                sides += 1; // Upper left corner
+            }
+            if diff_up && diff_right {
+                sides += 1; // Upper right corner
+            }
+            if diff_down && diff_left {
+                sides += 1; // Lower left corner
+            }
+            if diff_down && diff_right {
+                sides += 1; // Lower right corner
+            }
+
+            // Check if we have the other kind of inner corner where only one diagonal neighbor is
+            // different. Examples below show how this works when starting with the center O.
+            //
+            // XO.  .OX  ...  ...
+            // OO.  .OO  OO.  .OO
+            // ...  ...  XO.  .OX
+            if !diff_up && !diff_left && diff_up_left {
+                sides += 1; // Upper left inner corner
+            }
+            if !diff_up && !diff_right && diff_up_right {
+                sides += 1; // Upper right inner corner
+            }
+            if !diff_down && !diff_left && diff_down_left {
+                sides += 1; // Lower left inner corner
+            }
+            if !diff_down && !diff_right && diff_down_right {
+                sides += 1; // Lower right inner corner
+            }
+        }
+
+        trace!("{search}: {sides} sides");
+        sides
+    }
+
+    fn get_regions(&self) -> Vec<HashSet<Coord>> {
+        let mut regions = Vec::new();
+
+        // Keep track of the plots we have visited
+        let mut counted: HashSet<Coord> = HashSet::new();
+        for y in 1..=self.height {
+            for x in 1..=self.width {
+                let pos = (x, y);
+                if counted.contains(&pos) {
+                    continue;
+                }
+                let region = self.get_region(pos);
+                counted.extend(&region);
+                regions.push(region);
+            }
+        }
+        regions
+    }
+
+    /// Sums the area times the perimeter of every region.
+    pub fn fence_price(&self) -> usize {
+        let mut price = 0;
+
+        let regions = self.get_regions();
+        for region in regions {
+            price += self.get_area(&region) * self.get_perimeter(&region);
+        }
+
+        price
+    }
+
+    /// Sums the area times the number of sides of every region.
+    pub fn fence_price_sides(&self) -> usize {
+        let mut price = 0;
+
+        let regions = self.get_regions();
+        for region in regions {
+            price += self.get_area(&region) * self.get_sides(&region);
+        }
+        price
+    }
+}
+
+/// Day 12: Garden Groups.
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    type Parsed = Garden;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        Garden::new(&Input::from_lines(lines))
+    }
+
+    fn parse_input(input: &Input) -> Result<Self::Parsed, ParseError> {
+        Garden::new(input)
+    }
+
+    fn part1(parsed: Self::Parsed) -> Answer {
+        parsed.fence_price().into()
+    }
+
+    fn part2(parsed: Self::Parsed) -> Answer {
+        parsed.fence_price_sides().into()
+    }
+}
+
+#[test]
+fn test_invalid_plant() {
+    // A space would otherwise join up with the border
+    let err = Day12::parse(&["A A".to_string(), "AAA".to_string()]).unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (1, 2, " "));
+}
@@ -0,0 +1,29 @@
+// `size` random claw machines. About half of the prizes can be won with fewer than 100 presses
+// of each button, and the rest are just somewhere random.
+
+use aoc_common::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    while lines.len() < size * 4 {
+        let (ax, ay) = (rng.range(10..100), rng.range(10..100));
+        let (bx, by) = (rng.range(10..100), rng.range(10..100));
+        // The solver needs the buttons to move in different directions
+        if ax * by == ay * bx {
+            continue;
+        }
+        let (px, py) = if rng.chance(0.5) {
+            let (a, b) = (rng.range(0..100), rng.range(0..100));
+            (a * ax + b * bx, a * ay + b * by)
+        } else {
+            (rng.range(1000..20000), rng.range(1000..20000))
+        };
+        lines.push(format!("Button A: X+{ax}, Y+{ay}"));
+        lines.push(format!("Button B: X+{bx}, Y+{by}"));
+        lines.push(format!("Prize: X={px}, Y={py}"));
+        lines.push(String::new());
+    }
+    // No blank line after the last machine
+    lines.pop();
+    lines
+}
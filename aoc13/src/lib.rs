@@ -0,0 +1,349 @@
+//! Day 13: Claw Contraption. Finds the cheapest button presses that win each prize.
+
+pub mod generate;
+
+use aoc_common::{debug, nth_token, parse_token, Answer, ParseError, Solution};
+
+// Claw positions and token counts. The `checked` feature makes these 128 bits, so that any
+// input that parses can be solved without overflowing.
+#[cfg(feature = "checked")]
+pub type Num = i128;
+#[cfg(not(feature = "checked"))]
+pub type Num = isize;
+
+type Coord = (Num, Num);
+
+const BUTTON_A_COST: Num = 3;
+const BUTTON_B_COST: Num = 1;
+
+// Part 2 moves every prize much further away
+const PRIZE_OFFSET: Num = 10000000000000;
+
+const OVERFLOW: &str = "Overflows (the `checked` feature makes it 128 bits)";
+
+// Arithmetic where overflowing means the answer can't be worked out at all
+fn add(a: Num, b: Num) -> Result<Num, String> {
+    a.checked_add(b).ok_or_else(|| OVERFLOW.to_string())
+}
+
+fn sub(a: Num, b: Num) -> Result<Num, String> {
+    a.checked_sub(b).ok_or_else(|| OVERFLOW.to_string())
+}
+
+fn mul(a: Num, b: Num) -> Result<Num, String> {
+    a.checked_mul(b).ok_or_else(|| OVERFLOW.to_string())
+}
+
+/// One claw machine, with how far each button moves the claw and where the prize is.
+#[derive(Debug)]
+pub struct Claw {
+    button_a: Coord,
+    button_b: Coord,
+    prize: Coord,
+}
+
+impl Claw {
+    fn new(button_a: Coord, button_b: Coord, prize: Coord) -> Self {
+        Self {
+            button_a,
+            button_b,
+            prize,
+        }
+    }
+
+    /// Moves the prize to where part 2 says it really is, unless that's past what `Num` holds.
+    pub fn fix_prize(&mut self) -> Result<(), String> {
+        self.prize = (
+            add(self.prize.0, PRIZE_OFFSET)?,
+            add(self.prize.1, PRIZE_OFFSET)?,
+        );
+        Ok(())
+    }
+
+    /*
+        Find the lowest cost in A/B button presses
+        Solve the pair of equations instead of brute forcing.
+
+        a * ax + b * bx = px                            (1)
+        a * ay + b * by = py                            (2)
+
+        Hooray for first principles!
+
+            # start with (1)
+        a * ax + b * bx = px                            (1)
+            # subtract b * bx
+        a * ax = px - b * bx
+            # divide by ax
+        a = (px - b * bx) / ax                          (3)
+
+            # substitute (3) into (2)
+        (px - b * bx) / ax * ay + b * by = py
+            # multiply by ax
+        (px - b * bx) * ay + b * by * ax = py * ax
+            # expand
+        px * ay - b * bx * ay + b * by * ax = py * ax
+            # subtract px * ay
+        - b * bx * ay + b * by * ax = py * ax - px * ay
+            # factor out b on the lhs
+        b * (by * ax - bx * ay) = py * ax - px * ay
+            # divide by lhs (other than b term)
+        b = (py * ax - px * ay) / (by * ax - bx * ay)   (4)
+
+        # This gives us the following two final equations, which we can use.
+        # We need to later check that we got positive, integral solutions,
+        # which is easy to verify by plugging everything back into the first
+        # equations and checking versus the prize coordinates.
+
+        b = (py * ax - px * ay) / (by * ax - bx * ay)   (4)
+        a = (px - b * bx) / ax                          (3)
+
+        When the buttons move the claw along the same line (so the bottom of (4) is 0) or A never
+        moves in X (so (3) can't be used), those don't work, and line_presses and (2) are used
+        instead.
+
+        Everything is positive, so once a and b are known, anything that overflows is past the
+        prize. Only working out (4) can overflow without saying anything about the answer.
+    */
+    // How many times to press each button to reach the prize, if it can be reached
+    fn presses(&self) -> Result<Option<(Num, Num)>, String> {
+        let (ax, ay) = self.button_a;
+        let (bx, by) = self.button_b;
+        let (px, py) = self.prize;
+
+        let det = sub(mul(by, ax)?, mul(bx, ay)?)?;
+        if det == 0 {
+            return self.line_presses();
+        }
+        let b = sub(mul(py, ax)?, mul(px, ay)?)? / det;
+        if b < 0 {
+            return Ok(None);
+        }
+        // Nothing moves backwards, so if b presses already go past the prize then a would have
+        // to be negative
+        let a = if ax != 0 {
+            b.checked_mul(bx).map(|bx| (px - bx) / ax)
+        } else {
+            b.checked_mul(by).map(|by| (py - by) / ay)
+        };
+        let Some(a) = a.filter(|&a| a >= 0) else {
+            return Ok(None);
+        };
+
+        // Check to ensure it works
+        let total = |(u, v): Coord| a.checked_mul(u)?.checked_add(b.checked_mul(v)?);
+        debug!("prize: {:?} - a: {a} b: {b}", self.prize);
+        let reached = total((ax, bx)) == Some(px) && total((ay, by)) == Some(py);
+        Ok(reached.then_some((a, b)))
+    }
+
+    // With both buttons moving along one line, there can be lots of ways to reach the prize, so
+    // this picks the cheapest along whichever axis the buttons move in
+    fn line_presses(&self) -> Result<Option<(Num, Num)>, String> {
+        let (ax, ay) = self.button_a;
+        let (bx, by) = self.button_b;
+        let (px, py) = self.prize;
+
+        let presses = if (ax, bx) != (0, 0) {
+            line_presses(ax, bx, px)?
+        } else {
+            line_presses(ay, by, py)?
+        };
+        let Some((a, b)) = presses else {
+            return Ok(None);
+        };
+        // That only says how far along the line the claw goes, so the prize has to be on it
+        let total = |(u, v): Coord| a.checked_mul(u)?.checked_add(b.checked_mul(v)?);
+        Ok((total((ax, bx)) == Some(px) && total((ay, by)) == Some(py)).then_some((a, b)))
+    }
+
+    /// The fewest tokens that win the prize, or 0 if it can't be won. Fails if that can't be
+    /// worked out without overflowing.
+    pub fn get_cost(&self) -> Result<Num, String> {
+        match self.presses()? {
+            Some((a, b)) => add(mul(a, BUTTON_A_COST)?, mul(b, BUTTON_B_COST)?),
+            None => Ok(0),
+        }
+    }
+}
+
+// The gcd of `a` and `b`, along with x and y where a * x + b * y is the gcd
+fn ext_gcd(a: Num, b: Num) -> (Num, Num, Num) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+    let (g, x, y) = ext_gcd(b, a % b);
+    (g, y, x - (a / b) * y)
+}
+
+// The cheapest a and b where a * u + b * v = p, none of which are negative. Every solution is
+// some number of steps of v / g apart in a, and the cost only ever goes one way along them, so
+// the cheapest is either the fewest presses of A or the most. A product that overflows while
+// walking along the line is past `p`, so that can't be won.
+fn line_presses(u: Num, v: Num, p: Num) -> Result<Option<(Num, Num)>, String> {
+    Ok(match (u, v) {
+        (0, 0) => (p == 0).then_some((0, 0)),
+        (0, v) => (p % v == 0).then_some((0, p / v)),
+        (u, 0) => (p % u == 0).then_some((p / u, 0)),
+        (u, v) => {
+            let (g, x, _) = ext_gcd(u, v);
+            if p % g != 0 {
+                return Ok(None);
+            }
+            let step = v / g;
+            let mut a = mul(x.rem_euclid(step), (p / g).rem_euclid(step))? % step;
+            let Some(left) = a.checked_mul(u).map(|au| p - au).filter(|&left| left >= 0) else {
+                return Ok(None);
+            };
+            // A is only worth pressing more when it moves more than 3 times as far as B
+            if v.checked_mul(BUTTON_A_COST).is_some_and(|v| u > v) {
+                a += left / u.saturating_mul(step) * step;
+            }
+            Some((a, (p - a * u) / v))
+        }
+    })
+}
+
+/// The fewest tokens that win every prize that can be won, unless the total overflows.
+pub fn get_total_cost(claws: &[Claw]) -> Result<Num, String> {
+    claws
+        .iter()
+        .try_fold(0, |total, claw| add(total, claw.get_cost()?))
+}
+
+// Reads "X+57, Y+16" (or "X=3288, Y=1772" with '=' as the separator)
+fn read_coord(line_idx: usize, line: &str, text: &str, sep: char) -> Result<Coord, ParseError> {
+    let ctoks = text.split(", ").collect::<Vec<_>>();
+    let mut coord = [0; 2];
+    for (i, val) in coord.iter_mut().enumerate() {
+        let ctok = nth_token(line_idx, line, &ctoks, i)?;
+        let num = ctok.split(sep).collect::<Vec<_>>();
+        // Read as 64 bits, which keeps every product in get_cost within 128 bits with the
+        // `checked` feature. Without it, get_cost fails the part if anything overflows.
+        let tok = nth_token(line_idx, line, &num, 1)?;
+        let v: i64 = parse_token(line_idx, line, tok)?;
+        if v < 0 {
+            return Err(ParseError::new(line_idx, line, tok, "Can't be negative"));
+        }
+        *val = v as Num;
+    }
+    Ok((coord[0], coord[1]))
+}
+
+/// Reads each machine's `Button A`, `Button B` and `Prize` lines. With `fixed`, the prizes
+/// are moved to where part 2 says they are.
+pub fn read_claws(lines: &[String], fixed: bool) -> Result<Vec<Claw>, ParseError> {
+    let mut claws = Vec::new();
+
+    let mut button_a = (0, 0);
+    let mut button_b = (0, 0);
+    for (i, line) in lines.iter().enumerate() {
+        let toks = line.split(": ").collect::<Vec<_>>();
+        match toks[0] {
+            "Button A" => {
+                button_a = read_coord(i, line, nth_token(i, line, &toks, 1)?, '+')?;
+            }
+            "Button B" => {
+                button_b = read_coord(i, line, nth_token(i, line, &toks, 1)?, '+')?;
+            }
+            "Prize" => {
+                let prize = read_coord(i, line, nth_token(i, line, &toks, 1)?, '=')?;
+                let mut claw = Claw::new(button_a, button_b, prize);
+                if fixed {
+                    claw.fix_prize()
+                        .map_err(|e| ParseError::new(i, line, toks[1], e))?;
+                }
+                claws.push(claw);
+            }
+            "" => {}
+            _ => {
+                return Err(ParseError::new(i, line, toks[0], "Invalid input"));
+            }
+        }
+    }
+
+    Ok(claws)
+}
+
+/// Day 13: Claw Contraption.
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type Parsed = Vec<Claw>;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        read_claws(lines, false)
+    }
+
+    fn part1(parsed: Self::Parsed) -> Answer {
+        get_total_cost(&parsed).into()
+    }
+
+    fn part2(mut parsed: Self::Parsed) -> Answer {
+        parsed
+            .iter_mut()
+            .try_for_each(Claw::fix_prize)
+            .and_then(|_| get_total_cost(&parsed))
+            .into()
+    }
+}
+
+#[test]
+fn test_invalid_prize() {
+    let lines = [
+        "Button A: X+94, Y+34",
+        "Button B: X+22, Y+67",
+        "Prize: X=8400, Y=54o0",
+    ];
+    let lines = lines.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+    let err = read_claws(&lines, false).unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (3, 18, "54o0"));
+}
+
+#[test]
+fn test_negative_button() {
+    let lines = [
+        "Button A: X+94, Y+34",
+        "Button B: X+22, Y+-67",
+        "Prize: X=8400, Y=5400",
+    ];
+    let lines = lines.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+    let err = read_claws(&lines, false).unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 19, "-67"));
+}
+
+#[test]
+fn test_colinear_buttons() {
+    let cost = |a, b, prize| Claw::new(a, b, prize).get_cost().unwrap();
+    // B goes twice as far as A for a third of the cost, so it's pressed as much as possible
+    assert_eq!(cost((1, 1), (2, 2), (10, 10)), 5);
+    assert_eq!(cost((1, 1), (2, 2), (11, 11)), 8);
+    assert_eq!(cost((1, 1), (2, 2), (10, 11)), 0);
+    // Here A goes 4 times as far, so it's worth pressing as much as possible instead
+    assert_eq!(cost((4, 4), (1, 1), (9, 9)), 7);
+    assert_eq!(cost((0, 0), (0, 0), (1, 1)), 0);
+    // A doesn't move in X at all
+    assert_eq!(cost((0, 3), (2, 1), (4, 8)), 8);
+}
+
+#[test]
+fn test_cost_overflows() {
+    let lines = [
+        "Button A: X+1, Y+0".to_string(),
+        "Button B: X+0, Y+1".to_string(),
+        format!("Prize: X={}, Y=0", i64::MAX),
+    ];
+    let claws = read_claws(&lines, false).unwrap();
+    let fixed = read_claws(&lines, true);
+    #[cfg(feature = "checked")]
+    {
+        assert_eq!(get_total_cost(&claws), Ok(3 * i128::from(i64::MAX)));
+        assert!(fixed.is_ok());
+    }
+    #[cfg(not(feature = "checked"))]
+    {
+        assert!(get_total_cost(&claws).is_err());
+        assert_eq!(fixed.unwrap_err().line, 3);
+    }
+}
@@ -0,0 +1,15 @@
+// `size` random robots in the full 101 x 103 space. With very few robots they might all happen
+// to land in the corner that the example uses, and then they get treated as the example.
+
+use super::{HEIGHT, WIDTH};
+use aoc_common::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let (x, y) = (rng.below(WIDTH), rng.below(HEIGHT));
+            let (dx, dy) = (rng.range(-99..100), rng.range(-99..100));
+            format!("p={x},{y} v={dx},{dy}")
+        })
+        .collect()
+}
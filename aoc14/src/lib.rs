@@ -0,0 +1,246 @@
+//! Day 14: Restroom Redoubt. Moves security robots around a wrapping space.
+
+pub mod generate;
+
+use aoc_common::{
+    debug, nth_token, parse_token, Animate, Animation, Answer, Color, ParseError, Solution,
+};
+use std::fmt;
+
+const PRE_WIDTH: usize = 11;
+const PRE_HEIGHT: usize = 7;
+
+/// The width of the real input's space. The example uses a smaller one.
+pub const WIDTH: usize = 101;
+/// The height of the real input's space.
+pub const HEIGHT: usize = 103;
+
+// Discovered after looking at first several hundred inputs and seeing large vertical bands
+const TREE_START: usize = 83;
+
+const PALETTE: &[(char, Color)] = &[('*', Color::Green)];
+
+#[derive(Debug)]
+struct Coord {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Debug)]
+struct Velocity {
+    dx: isize,
+    dy: isize,
+}
+
+#[derive(Debug)]
+struct Robot {
+    pos: Coord,
+    vel: Velocity,
+}
+
+impl Robot {
+    fn new(i: usize, line: &str) -> Result<Self, ParseError> {
+        let toks: Vec<&str> = line.split_ascii_whitespace().collect();
+        let p_part: Vec<&str> = nth_token(i, line, &toks, 0)?.split("=").collect();
+        let v_part: Vec<&str> = nth_token(i, line, &toks, 1)?.split("=").collect();
+        let pos: Vec<&str> = nth_token(i, line, &p_part, 1)?.split(",").collect();
+        let vel: Vec<&str> = nth_token(i, line, &v_part, 1)?.split(",").collect();
+        let x = parse_token(i, line, nth_token(i, line, &pos, 0)?)?;
+        let y = parse_token(i, line, nth_token(i, line, &pos, 1)?)?;
+        let dx = parse_token(i, line, nth_token(i, line, &vel, 0)?)?;
+        let dy = parse_token(i, line, nth_token(i, line, &vel, 1)?)?;
+        Ok(Self {
+            pos: Coord { x, y },
+            vel: Velocity { dx, dy },
+        })
+    }
+
+    fn advance(&mut self, time: usize, width: usize, height: usize) {
+        if self.vel.dx > 0 {
+            let dx = (self.vel.dx as usize * time) % width;
+            self.pos.x = (self.pos.x + dx) % width;
+        } else {
+            let neg_dx = -self.vel.dx as usize;
+            let neg_dx = (neg_dx * time) % width;
+            if neg_dx > self.pos.x {
+                let dx = width - neg_dx;
+                self.pos.x += dx;
+            } else {
+                self.pos.x -= neg_dx;
+            }
+        }
+
+        if self.vel.dy > 0 {
+            let dy = (self.vel.dy as usize * time) % height;
+            self.pos.y = (self.pos.y + dy) % height;
+        } else {
+            let neg_dy = -self.vel.dy as usize;
+            let neg_dy = (neg_dy * time) % height;
+            if neg_dy > self.pos.y {
+                let dy = height - neg_dy;
+                self.pos.y += dy;
+            } else {
+                self.pos.y -= neg_dy;
+            }
+        }
+    }
+}
+
+/// The space, and every robot's position and velocity.
+pub struct Grid {
+    width: usize,
+    height: usize,
+    robots: Vec<Robot>,
+}
+
+impl Grid {
+    /// Reads one robot per line, like `p=0,4 v=3,-3`, into a `width` x `height` space.
+    pub fn create_grid(width: usize, height: usize, lines: &[String]) -> Result<Self, ParseError> {
+        let mut robots = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            let robot = Robot::new(i, line)?;
+            if robot.pos.x >= width || robot.pos.y >= height {
+                return Err(ParseError::new(i, line, line, "Robot is outside the grid"));
+            }
+            robots.push(robot);
+        }
+        Ok(Grid {
+            width,
+            height,
+            robots,
+        })
+    }
+
+    /// Moves every robot on by `time` seconds, wrapping around the edges.
+    pub fn advance_time(&mut self, time: usize) -> &mut Self {
+        self.robots
+            .iter_mut()
+            .for_each(|r| r.advance(time, self.width, self.height));
+        self
+    }
+
+    // Get the quadrant number for a given robot. Returns 1, 2, 3, or 4 for
+    // the normal quadrants, and 0 if we're on a midpoint line.
+    fn get_quadrant(&self, robot: &Robot) -> usize {
+        let mid_x = self.width / 2;
+        let mid_y = self.height / 2;
+        let (x, y) = (robot.pos.x, robot.pos.y);
+        if x == mid_x || y == mid_y {
+            return 0;
+        }
+        if x > mid_x {
+            if y < mid_y {
+                1
+            } else {
+                4
+            }
+        } else {
+            if y < mid_y {
+                2
+            } else {
+                3
+            }
+        }
+    }
+
+    /// Multiplies together the number of robots in each quadrant, ignoring the middle lines.
+    pub fn compute_safety(&self) -> usize {
+        let mut quadrants: [usize; 5] = [0; 5];
+        self.robots.iter().for_each(|r| {
+            quadrants[self.get_quadrant(r)] += 1;
+        });
+
+        debug!("quadrants: {quadrants:?}");
+
+        quadrants[1] * quadrants[2] * quadrants[3] * quadrants[4]
+    }
+
+    fn find_tree(&mut self) -> usize {
+        let mut iter = TREE_START;
+        self.advance_time(TREE_START);
+        while iter < self.width * self.height {
+            debug!("{iter} seconds:{self:?}");
+
+            self.advance_time(self.width);
+            iter += self.width;
+        }
+        0
+    }
+
+    fn rows(&self) -> Vec<String> {
+        let mut points = vec![vec![' '; self.width]; self.height];
+        for robot in &self.robots {
+            points[robot.pos.y][robot.pos.x] = '*';
+        }
+        points.into_iter().map(String::from_iter).collect()
+    }
+}
+
+impl fmt::Debug for Grid {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(fmt)?;
+        for row in self.rows() {
+            writeln!(fmt, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints the frame with the tree in it. The candidate frames for part 2 are logged at debug
+/// level on the way, with `AOC_LOG=aoc14=debug`.
+//
+// Part 2 was solved by eye, by dumping frames from find_tree() and looking for the picture
+pub fn show_tree(lines: &[String]) -> Result<(), ParseError> {
+    Grid::create_grid(WIDTH, HEIGHT, lines)?.find_tree();
+    let mut grid = Grid::create_grid(WIDTH, HEIGHT, lines)?;
+    grid.advance_time(6446);
+    println!("{:?}", grid);
+    Ok(())
+}
+
+/// Day 14: Restroom Redoubt.
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+
+    type Parsed = Grid;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        // The example robots all fit in a much smaller space than the real input
+        let robots = lines
+            .iter()
+            .enumerate()
+            .map(|(i, l)| Robot::new(i, l))
+            .collect::<Result<Vec<_>, _>>()?;
+        if robots
+            .iter()
+            .all(|r| r.pos.x < PRE_WIDTH && r.pos.y < PRE_HEIGHT)
+        {
+            Grid::create_grid(PRE_WIDTH, PRE_HEIGHT, lines)
+        } else {
+            Grid::create_grid(WIDTH, HEIGHT, lines)
+        }
+    }
+
+    fn part1(mut parsed: Self::Parsed) -> Answer {
+        parsed.advance_time(100).compute_safety().into()
+    }
+}
+
+impl Animate for Day14 {
+    // Part 1 is every second up to the safety check, and part 2 is the frames find_tree() shows
+    fn animate(mut parsed: Self::Parsed, part: u32) -> Animation {
+        let mut animation = Animation::new(PALETTE);
+        let (start, step, end) = match part {
+            1 => (0, 1, 100),
+            _ => (TREE_START, parsed.width, parsed.width * parsed.height - 1),
+        };
+        parsed.advance_time(start);
+        for time in (start..=end).step_by(step) {
+            animation.push(format!("{time} seconds"), parsed.rows());
+            parsed.advance_time(step);
+        }
+        animation
+    }
+}
@@ -0,0 +1,31 @@
+// A random `size` x `size` warehouse, walled in, with scattered walls and boxes and the robot
+// somewhere inside. The moves follow in lines of 1000, 8 moves for every tile of the warehouse.
+
+use aoc_common::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    // Leave room for the walls and the robot
+    let size = size.max(3);
+    let mut grid = vec![vec!['#'; size]; size];
+    for row in &mut grid[1..size - 1] {
+        for tile in &mut row[1..size - 1] {
+            *tile = match rng.below(20) {
+                0 => '#',
+                1..=4 => 'O',
+                _ => '.',
+            };
+        }
+    }
+    grid[rng.below(size - 2) + 1][rng.below(size - 2) + 1] = '@';
+
+    let mut lines: Vec<String> = grid
+        .into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect();
+    lines.push(String::new());
+    let moves: Vec<char> = (0..size * size * 8)
+        .map(|_| *rng.choose(&['<', '>', '^', 'v']))
+        .collect();
+    lines.extend(moves.chunks(1000).map(|chunk| chunk.iter().collect()));
+    lines
+}
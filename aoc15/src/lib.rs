@@ -0,0 +1,257 @@
+//! Day 15: Warehouse Woes. Follows a robot pushing boxes around a warehouse.
+
+pub mod generate;
+
+use aoc_common::{Animate, Animation, Answer, Color, Coord, Direction, Grid, ParseError, Solution};
+
+const PALETTE: &[(char, Color)] = &[
+    ('#', Color::Dim),
+    ('O', Color::Yellow),
+    ('[', Color::Yellow),
+    (']', Color::Yellow),
+    ('@', Color::Red),
+];
+
+/// The warehouse map, the robot's position in it, and the moves it will make.
+#[derive(Debug)]
+pub struct Warehouse {
+    grid: Grid,
+    robot: Coord,
+
+    // This almost seems like it doesn't belong in this struct, due to having
+    // to clone it to iterate (while mutating the rest of the struct with
+    // other helper functions).
+    moves: Vec<char>,
+}
+
+impl Warehouse {
+    /// Reads the map, then a blank line, then the moves. With `expand`, everything except the
+    /// robot is made twice as wide, as in part 2.
+    pub fn new(lines: &[String], expand: bool) -> Result<Self, ParseError> {
+        let warehouse = Self::read(lines)?;
+        Ok(if expand {
+            warehouse.expand()
+        } else {
+            warehouse
+        })
+    }
+
+    fn read(lines: &[String]) -> Result<Self, ParseError> {
+        // The grid comes first, followed by a blank line and the moves
+        let height = lines
+            .iter()
+            .position(|l| l.is_empty())
+            .unwrap_or(lines.len());
+        let grid = Grid::from_lines(&lines[..height], None, |c| {
+            matches!(c, '#' | '.' | 'O' | '@').then_some(c)
+        })?;
+
+        // There's no border, so the outer walls are what keep the robot in bounds
+        for (x, y) in grid.positions() {
+            let edge = x == 0 || y == 0 || x == grid.width() - 1 || y == grid.height() - 1;
+            if edge && grid[(x, y)] != '#' {
+                let line = &lines[y];
+                let text = &line[x..x + 1];
+                return Err(ParseError::new(
+                    y,
+                    line,
+                    text,
+                    "Warehouse must be walled in",
+                ));
+            }
+        }
+        let mut robots = grid.positions().filter(|&pos| grid[pos] == '@');
+        let robot = robots
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(lines, "No robot found"))?;
+        if let Some((x, y)) = robots.next() {
+            let line = &lines[y];
+            return Err(ParseError::new(y, line, &line[x..x + 1], "Multiple robots"));
+        }
+
+        // Get the moves next
+        let mut moves = Vec::new();
+        for (y, line) in lines.iter().enumerate().skip(height) {
+            for (x, c) in line.char_indices() {
+                if Direction::from_arrow(c).is_none() {
+                    let text = &line[x..x + c.len_utf8()];
+                    return Err(ParseError::new(y, line, text, "Unknown move direction"));
+                }
+                moves.push(c);
+            }
+        }
+
+        Ok(Self { grid, robot, moves })
+    }
+
+    /// Makes everything except the robot twice as wide, as in part 2.
+    pub fn expand(&self) -> Self {
+        let mut grid = Grid::new(self.grid.width() * 2, self.grid.height(), '.');
+        let mut robot = self.robot;
+        for (x, y) in self.grid.positions() {
+            let (l, r) = match self.grid[(x, y)] {
+                'O' => ('[', ']'),
+                '@' => {
+                    robot = (x * 2, y);
+                    ('@', '.')
+                }
+                c => (c, c),
+            };
+            grid[(x * 2, y)] = l;
+            grid[(x * 2 + 1, y)] = r;
+        }
+
+        Self {
+            grid,
+            robot,
+            moves: self.moves.clone(),
+        }
+    }
+
+    fn get_next_coord(dir: char, pos: Coord) -> Coord {
+        match Direction::from_arrow(dir) {
+            Some(dir) => dir.step(pos),
+            None => panic!("Unknown move direction {}", dir),
+        }
+    }
+
+    fn update_grid(&mut self, pos: Coord, item: char) {
+        self.grid[pos] = item;
+        if item == '@' {
+            self.robot = pos;
+        }
+    }
+
+    fn move_item(&mut self, dir: char, pos: Coord, just_check: bool) -> bool {
+        let item = self.grid[pos];
+        match item {
+            '.' => true,
+            '#' => false,
+            'O' | '@' => {
+                let new_pos = Self::get_next_coord(dir, pos);
+                if self.move_item(dir, new_pos, just_check) {
+                    self.update_grid(pos, '.');
+                    self.update_grid(new_pos, item);
+                    true
+                } else {
+                    false
+                }
+            }
+            '[' => {
+                let new_l_pos = Self::get_next_coord(dir, pos);
+                if dir == '<' {
+                    let old_r_pos = (pos.0 + 1, pos.1);
+                    let new_r_pos = pos;
+                    if self.move_item(dir, new_l_pos, just_check) {
+                        self.update_grid(old_r_pos, '.');
+                        self.update_grid(new_l_pos, '[');
+                        self.update_grid(new_r_pos, ']');
+                        return true;
+                    } else {
+                        return false;
+                    }
+                } else if dir == '>' {
+                    // Skip ahead over the right side of the box in this case
+                    let empty_pos = (pos.0 - 1, pos.1);
+                    let new_r_pos = Self::get_next_coord(dir, new_l_pos);
+                    if self.move_item(dir, new_r_pos, just_check) {
+                        self.update_grid(empty_pos, '.');
+                        self.update_grid(new_l_pos, '[');
+                        self.update_grid(new_r_pos, ']');
+                        return true;
+                    } else {
+                        return false;
+                    }
+                } else if self.move_item(dir, new_l_pos, true) {
+                    // We need to check both halves
+                    let old_l_pos = pos;
+                    let old_r_pos = (pos.0 + 1, pos.1);
+                    let new_r_pos = Self::get_next_coord(dir, old_r_pos);
+                    if self.move_item(dir, new_r_pos, true) {
+                        if just_check {
+                            return true;
+                        }
+                        self.move_item(dir, new_l_pos, false);
+                        self.move_item(dir, new_r_pos, false);
+                        self.update_grid(old_l_pos, '.');
+                        self.update_grid(old_r_pos, '.');
+                        self.update_grid(new_l_pos, '[');
+                        self.update_grid(new_r_pos, ']');
+                        return true;
+                    }
+                }
+                false
+            }
+            ']' => {
+                // Forward operation to the '[' case instead.
+                self.move_item(dir, (pos.0 - 1, pos.1), just_check)
+            }
+            _ => panic!("Unknown contents at {:?}: {}", pos, self.grid[pos]),
+        }
+    }
+
+    /// Makes each of the robot's moves in turn. Records a frame after every move, if there's an
+    /// animation to add to.
+    pub fn move_robot(&mut self, mut animation: Option<&mut Animation>) -> &Self {
+        if let Some(animation) = animation.as_deref_mut() {
+            animation.push_grid("Start", &self.grid);
+        }
+
+        // There's no great way (that I know) to handle iterating over the
+        // moves, while mutating the rest of the data structure itself.
+        let moves = self.moves.clone();
+        for (i, &dir) in moves.iter().enumerate() {
+            self.move_item(dir, self.robot, false);
+            if let Some(animation) = animation.as_deref_mut() {
+                let caption = format!("Move {}/{}: {dir}", i + 1, moves.len());
+                animation.push_grid(caption, &self.grid);
+            }
+        }
+        self
+    }
+
+    /// Sums the GPS coordinate (100 times the row plus the column) of every box.
+    pub fn compute_gps(&self) -> usize {
+        let mut gps = 0;
+        for (x, y) in self.grid.positions() {
+            if matches!(self.grid[(x, y)], 'O' | '[') {
+                gps += y * 100 + x;
+            }
+        }
+
+        gps
+    }
+}
+
+/// Day 15: Warehouse Woes.
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    type Parsed = Warehouse;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        Warehouse::new(lines, false)
+    }
+
+    fn part1(mut parsed: Self::Parsed) -> Answer {
+        parsed.move_robot(None).compute_gps().into()
+    }
+
+    fn part2(parsed: Self::Parsed) -> Answer {
+        parsed.expand().move_robot(None).compute_gps().into()
+    }
+}
+
+impl Animate for Day15 {
+    fn animate(parsed: Self::Parsed, part: u32) -> Animation {
+        let mut animation = Animation::new(PALETTE);
+        let mut warehouse = match part {
+            1 => parsed,
+            _ => parsed.expand(),
+        };
+        warehouse.move_robot(Some(&mut animation));
+        animation
+    }
+}
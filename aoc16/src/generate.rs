@@ -0,0 +1,41 @@
+// A random `size` x `size` maze, carved out with a depth first search so that the end is always
+// reachable, and then with some extra walls knocked out so that there's more than one way through.
+// The start is in the bottom left corner and the end in the top right, like the real input.
+
+use aoc_common::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    // The carved cells sit on odd coordinates, inside an odd sized maze
+    let size = size.max(5) | 1;
+    let mut grid = vec![vec!['#'; size]; size];
+    let mut stack: Vec<(usize, usize)> = vec![(1, size - 2)];
+    grid[size - 2][1] = '.';
+    while let Some(&(x, y)) = stack.last() {
+        let unvisited: Vec<(usize, usize)> = [(0, -2), (2, 0), (0, 2), (-2, 0)]
+            .into_iter()
+            .filter_map(|(dx, dy)| {
+                let nx = x.checked_add_signed(dx).filter(|&nx| nx < size - 1)?;
+                let ny = y.checked_add_signed(dy).filter(|&ny| ny < size - 1)?;
+                (grid[ny][nx] == '#').then_some((nx, ny))
+            })
+            .collect();
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let (nx, ny) = *rng.choose(&unvisited);
+        grid[(y + ny) / 2][(x + nx) / 2] = '.';
+        grid[ny][nx] = '.';
+        stack.push((nx, ny));
+    }
+
+    for _ in 0..size * size / 20 {
+        let (x, y) = (rng.below(size - 2) + 1, rng.below(size - 2) + 1);
+        grid[y][x] = '.';
+    }
+    grid[size - 2][1] = 'S';
+    grid[1][size - 2] = 'E';
+    grid.into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect()
+}
@@ -0,0 +1,256 @@
+//! Day 16: Reindeer Maze. Finds the cheapest paths through a maze where turning is costly.
+
+pub mod generate;
+
+use aoc_common::{
+    dijkstra, Animate, Animation, Answer, Color, Coord, Direction, Grid, Input, ParseError,
+    ShortestPaths, Solution,
+};
+use std::collections::HashSet;
+
+// Moving forward costs 1 point, and each rotation costs 1000
+const STEP_COST: usize = 1;
+const ROTATE_COST: usize = 1000;
+
+const UNREACHABLE: &str = "The end can't be reached";
+
+const PALETTE: &[(char, Color)] = &[
+    ('#', Color::Dim),
+    ('S', Color::Yellow),
+    ('E', Color::Yellow),
+    ('O', Color::Green),
+    ('^', Color::Cyan),
+    ('>', Color::Cyan),
+    ('v', Color::Cyan),
+    ('<', Color::Cyan),
+];
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Reindeer {
+    pos: Coord,
+    face: Direction,
+}
+
+impl Reindeer {
+    fn new(pos: Coord, face: Direction) -> Self {
+        Self { pos, face }
+    }
+
+    fn move_forward(self) -> Self {
+        Self::new(self.face.step(self.pos), self.face)
+    }
+
+    fn move_left(self) -> Self {
+        Self::new(self.pos, self.face.turn_left()).move_forward()
+    }
+
+    fn move_right(self) -> Self {
+        Self::new(self.pos, self.face.turn_right()).move_forward()
+    }
+}
+
+fn in_bounds(grid: &Grid, pos: Coord) -> bool {
+    match grid[pos] {
+        '#' => false,
+        '.' => true,
+        'S' | 'E' => true,
+        c => panic!("Invalid char: {c}"),
+    }
+}
+
+fn possible_moves(grid: &Grid, r: Reindeer) -> Vec<(Reindeer, usize)> {
+    [
+        (r.move_forward(), STEP_COST),
+        (r.move_left(), ROTATE_COST + STEP_COST),
+        (r.move_right(), ROTATE_COST + STEP_COST),
+    ]
+    .into_iter()
+    .filter(|(r_move, _)| in_bounds(grid, r_move.pos))
+    .collect()
+}
+
+/// The maze, with its start and end tiles.
+#[derive(Debug)]
+pub struct Maze {
+    grid: Grid,
+    start: Coord,
+    end: Coord,
+}
+
+impl Maze {
+    /// Reads the maze, which must be walled in and have one `S` and one `E`.
+    pub fn new(input: &Input) -> Result<Self, ParseError> {
+        let lines = input.lines();
+        let grid = Grid::from_bytes(&lines, None, |b| {
+            matches!(b, b'#' | b'.' | b'S' | b'E').then_some(char::from(b))
+        })?;
+        let width = grid.width();
+        let height = grid.height();
+        let unknown = (0, 0);
+
+        // There is no border, so the outer walls keep the reindeer in bounds
+        for (x, y) in grid.positions() {
+            let edge = x == 0 || y == 0 || x == width - 1 || y == height - 1;
+            if edge && grid[(x, y)] != '#' {
+                let line = lines[y];
+                let text = &line[x..x + 1];
+                return Err(ParseError::new_bytes(
+                    y,
+                    line,
+                    text,
+                    "Maze must be walled in",
+                ));
+            }
+        }
+
+        let mut maze = Self {
+            grid,
+            start: unknown,
+            end: unknown,
+        };
+        maze.start = maze
+            .find('S')
+            .ok_or_else(|| ParseError::end_of_input(&lines, "Maze has no start"))?;
+        maze.end = maze
+            .find('E')
+            .ok_or_else(|| ParseError::end_of_input(&lines, "Maze has no end"))?;
+
+        Ok(maze)
+    }
+
+    fn find(&self, c: char) -> Option<Coord> {
+        self.grid.find(&c)
+    }
+
+    fn search(&self) -> ShortestPaths<Reindeer> {
+        let start = Reindeer::new(self.start, Direction::Right);
+        dijkstra(start, |&r| possible_moves(&self.grid, r))
+    }
+
+    // We can reach the end facing any direction, so returns the best score along with every
+    // reindeer state that achieves it, unless the end can't be reached at all
+    fn best_ends(&self, paths: &ShortestPaths<Reindeer>) -> Option<(usize, Vec<Reindeer>)> {
+        let ends = Direction::ALL.map(|face| Reindeer::new(self.end, face));
+        let score = ends.iter().filter_map(|r| paths.cost(r)).min()?;
+        let best = ends
+            .into_iter()
+            .filter(|r| paths.cost(r) == Some(score))
+            .collect();
+        Some((score, best))
+    }
+
+    /// The lowest score from the start (facing east) to the end, or `None` if it can't be
+    /// reached.
+    pub fn best_score(&self) -> Option<usize> {
+        let (score, _) = self.best_ends(&self.search())?;
+
+        Some(score)
+    }
+
+    /// Counts the tiles that lie on at least one of the lowest scoring paths, or `None` if the
+    /// end can't be reached.
+    pub fn best_path_tiles(&self) -> Option<usize> {
+        let paths = self.search();
+        let (_, ends) = self.best_ends(&paths)?;
+
+        // Walk the predecessor DAG back from every best end, ignoring which way we faced
+        let tiles = paths
+            .on_paths_to(&ends)
+            .into_iter()
+            .map(|r| r.pos)
+            .collect::<HashSet<_>>();
+
+        Some(tiles.len())
+    }
+
+    // Walks one of the cheapest paths, drawing the way the reindeer faces at each step
+    fn animate_best_path(&self, animation: &mut Animation) {
+        let paths = self.search();
+        let ends = self.best_ends(&paths);
+        let Some(path) = ends.and_then(|(_, ends)| paths.path_to(ends.first()?)) else {
+            animation.push_grid(UNREACHABLE, &self.grid);
+            return;
+        };
+
+        let mut grid = self.grid.clone();
+        for r in path {
+            if grid[r.pos] == '.' {
+                grid[r.pos] = r.face.arrow();
+            }
+            let score = paths.cost(&r).unwrap();
+            animation.push_grid(format!("Score {score}"), &grid);
+        }
+    }
+
+    // Fills in the tiles on every cheapest path in order of score, with a frame for each score
+    fn animate_best_tiles(&self, animation: &mut Animation) {
+        let paths = self.search();
+        let Some((_, ends)) = self.best_ends(&paths) else {
+            animation.push_grid(UNREACHABLE, &self.grid);
+            return;
+        };
+        let mut states = paths.on_paths_to(&ends).into_iter().collect::<Vec<_>>();
+        states.sort_by_key(|r| (paths.cost(r), *r));
+
+        let mut grid = self.grid.clone();
+        let mut tiles = HashSet::new();
+        for same_score in states.chunk_by(|a, b| paths.cost(a) == paths.cost(b)) {
+            for r in same_score {
+                if grid[r.pos] == '.' {
+                    grid[r.pos] = 'O';
+                }
+                tiles.insert(r.pos);
+            }
+            let score = paths.cost(&same_score[0]).unwrap();
+            let caption = format!("Score {score}: {} tiles", tiles.len());
+            animation.push_grid(caption, &grid);
+        }
+    }
+}
+
+/// Day 16: Reindeer Maze.
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+
+    type Parsed = Maze;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        Maze::new(&Input::from_lines(lines))
+    }
+
+    fn parse_input(input: &Input) -> Result<Self::Parsed, ParseError> {
+        Maze::new(input)
+    }
+
+    fn part1(parsed: Self::Parsed) -> Answer {
+        parsed.best_score().ok_or(UNREACHABLE.to_string()).into()
+    }
+
+    fn part2(parsed: Self::Parsed) -> Answer {
+        parsed
+            .best_path_tiles()
+            .ok_or(UNREACHABLE.to_string())
+            .into()
+    }
+}
+
+impl Animate for Day16 {
+    fn animate(parsed: Self::Parsed, part: u32) -> Animation {
+        let mut animation = Animation::new(PALETTE);
+        match part {
+            1 => parsed.animate_best_path(&mut animation),
+            _ => parsed.animate_best_tiles(&mut animation),
+        }
+        animation
+    }
+}
+
+#[test]
+fn test_unreachable() {
+    let lines = ["#####", "#S#E#", "#####"].map(String::from);
+    let maze = Day16::parse(&lines).unwrap();
+    assert_eq!(maze.best_score(), None);
+    assert_eq!(Day16::part1(maze), Answer::Error(UNREACHABLE.to_string()));
+}
@@ -0,0 +1,63 @@
+// A random program shaped like the real input: each loop turns the low bits of A into one output,
+// then shifts A right by 3. The constants are picked at random until the program has a quine, so
+// part 2 always has an answer. `size` is ignored, since the program is always 16 values long.
+
+use super::Computer;
+use aoc_common::Rng;
+
+fn input_lines(register_a: u64, program: &[u8]) -> Vec<String> {
+    let program: Vec<String> = program.iter().map(|v| v.to_string()).collect();
+    vec![
+        format!("Register A: {register_a}"),
+        "Register B: 0".to_string(),
+        "Register C: 0".to_string(),
+        String::new(),
+        format!("Program: {}", program.join(",")),
+    ]
+}
+
+// Builds A up 3 bits at a time, matching the program from its last value back to its first
+fn has_quine(computer: &mut Computer) -> bool {
+    let program = computer.program.clone();
+    let mut candidates = vec![0];
+    for idx in (0..program.len()).rev() {
+        let mut next = Vec::new();
+        for a in candidates {
+            for bits in 0..8 {
+                let try_a = a * 8 + bits;
+                computer.reset(try_a, 0, 0);
+                computer.run_program();
+                if computer.output == program[idx..] {
+                    next.push(try_a);
+                }
+            }
+        }
+        candidates = next;
+    }
+    candidates.iter().any(|&a| a != 0)
+}
+
+pub fn generate(rng: &mut Rng, _size: usize) -> Vec<String> {
+    loop {
+        // B = A % 8, B ^= k1, C = A >> B, then B ^= k2 and B ^= C in either order
+        let mut program = vec![2, 4, 1, rng.below(8) as u8, 7, 5];
+        let (xor_k2, xor_c) = ([1, rng.below(8) as u8], [4, rng.below(8) as u8]);
+        if rng.chance(0.5) {
+            program.extend(xor_k2.into_iter().chain(xor_c));
+        } else {
+            program.extend(xor_c.into_iter().chain(xor_k2));
+        }
+        // A >>= 3 and output B can go in either order too, before jumping back to the start
+        if rng.chance(0.5) {
+            program.extend([0, 3, 5, 5, 3, 0]);
+        } else {
+            program.extend([5, 5, 0, 3, 3, 0]);
+        }
+
+        let lines = input_lines(rng.next_u64() >> 16, &program);
+        let mut computer = Computer::new(&lines).unwrap();
+        if has_quine(&mut computer) {
+            return lines;
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// A random list of falling bytes. Fewer than 1024 bytes are treated as the 7 x 7 example space
+// and the rest as the full 71 x 71 space, so `size` picks between those too. Either way, the
+// path is still open after the bytes that part 1 drops, and eventually gets cut off for part 2.
+// That can take more than `size` bytes, in which case the list is longer.
+
+use super::{BYTES, DIM, PRE_BYTES, PRE_DIM};
+use aoc_common::Rng;
+use std::collections::VecDeque;
+
+// Whether there's still a way from the top left corner to the bottom right
+fn has_path(side: usize, bytes: &[(usize, usize)]) -> bool {
+    let mut blocked = vec![vec![false; side]; side];
+    for &(x, y) in bytes {
+        blocked[y][x] = true;
+    }
+    let mut queue = VecDeque::from([(0, 0)]);
+    blocked[0][0] = true;
+    while let Some((x, y)) = queue.pop_front() {
+        if (x, y) == (side - 1, side - 1) {
+            return true;
+        }
+        for (dx, dy) in [(0, -1), (1, 0), (0, 1), (-1, 0)] {
+            let (Some(nx), Some(ny)) = (x.checked_add_signed(dx), y.checked_add_signed(dy)) else {
+                continue;
+            };
+            if nx < side && ny < side && !blocked[ny][nx] {
+                blocked[ny][nx] = true;
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+    false
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let (side, dropped) = if size < BYTES {
+        (PRE_DIM - 1, PRE_BYTES)
+    } else {
+        (DIM - 1, BYTES)
+    };
+    let mut cells: Vec<(usize, usize)> = (0..side)
+        .flat_map(|y| (0..side).map(move |x| (x, y)))
+        .filter(|&pos| pos != (0, 0) && pos != (side - 1, side - 1))
+        .collect();
+    loop {
+        rng.shuffle(&mut cells);
+        if !has_path(side, &cells[..dropped]) {
+            continue;
+        }
+
+        // Binary search for the byte that cuts off the exit
+        let (mut open, mut closed) = (dropped, cells.len());
+        while closed > open + 1 {
+            let mid = (open + closed) / 2;
+            if has_path(side, &cells[..mid]) {
+                open = mid;
+            } else {
+                closed = mid;
+            }
+        }
+        let count = size.clamp(closed, cells.len());
+        return cells[..count]
+            .iter()
+            .map(|(x, y)| format!("{x},{y}"))
+            .collect();
+    }
+}
@@ -0,0 +1,281 @@
+//! Day 18: RAM Run. Finds a path through memory as bytes fall into it.
+
+pub mod generate;
+
+use aoc_common::{
+    astar, parse_token, Animate, Animation, Answer, Color, Coord, Grid, ParseError, Solution,
+};
+
+// Use a corrupted byte as a border to simplify bounds checking of indices
+const INVALID: char = '#';
+
+// The example is a 7x7 space that drops 12 bytes, while the real input is 71x71 and drops 1024.
+// Each dimension includes room for the border.
+/// The size of the example's space, including its border.
+pub const PRE_DIM: usize = 8;
+const PRE_BYTES: usize = 12;
+/// The size of the real input's space, including its border.
+pub const DIM: usize = 72;
+const BYTES: usize = 1024;
+
+// The byte that finally cuts off the exit is drawn as '@'
+const PALETTE: &[(char, Color)] = &[('#', Color::Dim), ('O', Color::Green), ('@', Color::Red)];
+
+fn in_bounds(grid: &Grid, pos: Coord) -> bool {
+    match grid[pos] {
+        '#' => false,
+        '.' => true,
+        'S' | 'E' => true,
+        c => panic!("Invalid char: {c}"),
+    }
+}
+
+fn possible_moves(grid: &Grid, pos: Coord) -> Vec<(Coord, usize)> {
+    grid.neighbors4(pos)
+        .filter(|&next| in_bounds(grid, next))
+        .map(|next| (next, 1))
+        .collect()
+}
+
+/// The memory space, and the bytes that will fall into it in order.
+#[derive(Debug)]
+pub struct Memory {
+    grid: Grid,
+    width: usize,
+    height: usize,
+    start: Coord,
+    end: Coord,
+    corrupted: Vec<Coord>,
+    num_corrupted: usize,
+}
+
+impl Memory {
+    /// Reads one `x,y` byte per line. `dim` is the size of the space plus its border, so
+    /// `PRE_DIM` for the example and `DIM` for the real input.
+    pub fn new(lines: &[String], dim: usize) -> Result<Self, ParseError> {
+        // Create an empty fill to make the space usable, with borders around everything
+        let grid = Grid::with_border(dim - 1, dim - 1, '.', INVALID);
+        let height = dim;
+        let width = dim;
+        let mut corrupted = Vec::new();
+        let num_corrupted = 0;
+
+        let start = (1, 1);
+        let end = (dim - 1, dim - 1);
+
+        for (i, line) in lines.iter().enumerate() {
+            let (x_str, y_str) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::new(i, line, line, "Expected x,y"))?;
+            let x = parse_token::<usize>(i, line, x_str)?;
+            let y = parse_token::<usize>(i, line, y_str)?;
+            // The border takes up the last row and column
+            if x >= dim - 1 || y >= dim - 1 {
+                return Err(ParseError::new(i, line, line, "Byte falls outside memory"));
+            }
+            corrupted.push((x, y));
+        }
+
+        Ok(Self {
+            grid,
+            width,
+            height,
+            start,
+            end,
+            corrupted,
+            num_corrupted,
+        })
+    }
+
+    fn reset_grid(&mut self) {
+        for y in 1..self.height {
+            for x in 1..self.width {
+                self.grid[y][x] = '.';
+            }
+        }
+        self.num_corrupted = 0;
+    }
+
+    /// Drops the next `num` bytes into memory. Panics if fewer than `num` are left to fall.
+    pub fn corrupt(&mut self, num: usize) -> &mut Self {
+        if self.num_corrupted + num > self.corrupted.len() {
+            panic!("Attempt to corrupt too far");
+        }
+        for &(x, y) in self.corrupted[self.num_corrupted..].iter().take(num) {
+            self.grid[y + 1][x + 1] = INVALID;
+        }
+        self.num_corrupted += num;
+        self
+    }
+
+    fn search(&self) -> Option<(usize, Vec<Coord>)> {
+        let end = self.end;
+        let distance = |&(x, y): &Coord| end.0.abs_diff(x) + end.1.abs_diff(y);
+        astar(
+            self.start,
+            |&pos| pos == end,
+            |&pos| possible_moves(&self.grid, pos),
+            distance,
+        )
+    }
+
+    /// The fewest steps from the top left corner to the bottom right, or `None` when the bytes
+    /// have cut off the exit.
+    pub fn shortest_path(&self) -> Option<usize> {
+        self.search().map(|(steps, _)| steps)
+    }
+
+    // Records the current space, with the shortest path through it if there is one
+    fn record(&self, animation: &mut Animation, caption: &str) {
+        match self.search() {
+            Some((steps, path)) => {
+                let mut grid = self.grid.clone();
+                for pos in path {
+                    grid[pos] = 'O';
+                }
+                animation.push_grid(format!("{caption}: {steps} steps"), &grid);
+            }
+            None => animation.push_grid(format!("{caption}: cut off"), &self.grid),
+        }
+    }
+
+    // Drops the bytes one at a time, and then shows the path that's left
+    fn animate_bytes(&mut self, bytes: usize, animation: &mut Animation) {
+        self.reset_grid();
+        for i in 0..bytes {
+            let (x, y) = self.corrupted[i];
+            self.corrupt(1);
+            animation.push_grid(format!("Byte {} at {x},{y}", i + 1), &self.grid);
+        }
+        self.record(animation, &format!("After {bytes} bytes"));
+    }
+
+    /// Finds the first byte that cuts off the exit, given that the first `base_corrupt` bytes
+    /// don't. Records each step of the binary search, if there's an animation to add to. Fails if
+    /// those first bytes already cut it off, or if it's never cut off at all.
+    pub fn get_corrupt_coord(
+        &mut self,
+        base_corrupt: usize,
+        mut animation: Option<&mut Animation>,
+    ) -> Result<Coord, String> {
+        let mut lower = base_corrupt;
+        let mut upper = self.corrupted.len();
+        self.reset_grid();
+        if self.corrupt(lower).shortest_path().is_none() {
+            return Err(format!("The first {lower} bytes already cut off the exit"));
+        }
+        self.reset_grid();
+        if self.corrupt(upper).shortest_path().is_some() {
+            return Err(format!("The exit is still open after all {upper} bytes"));
+        }
+
+        // The exit is open after `lower` bytes and cut off after `upper`
+        while upper > lower + 1 {
+            let c = (upper + lower) / 2;
+            self.reset_grid();
+            self.corrupt(c);
+            if let Some(animation) = animation.as_deref_mut() {
+                self.record(animation, &format!("Trying {c} bytes"));
+            }
+            if self.shortest_path().is_none() {
+                upper = c;
+            } else {
+                lower = c;
+            }
+        }
+
+        let (x, y) = self.corrupted[lower];
+        if let Some(animation) = animation {
+            self.reset_grid();
+            self.corrupt(upper);
+            let mut grid = self.grid.clone();
+            grid[y + 1][x + 1] = '@';
+            animation.push_grid(format!("Byte {upper} at {x},{y} cuts off the exit"), &grid);
+        }
+        Ok((x, y))
+    }
+}
+
+/// Day 18: RAM Run.
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+
+    type Parsed = (Memory, usize);
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        // The real puzzle drops 1024 bytes, so anything shorter than that is the example
+        if lines.len() < PRE_BYTES {
+            Err(ParseError::end_of_input(
+                lines,
+                format!("Expected at least {PRE_BYTES} bytes"),
+            ))
+        } else if lines.len() < BYTES {
+            Ok((Memory::new(lines, PRE_DIM)?, PRE_BYTES))
+        } else {
+            Ok((Memory::new(lines, DIM)?, BYTES))
+        }
+    }
+
+    fn part1((mut memory, bytes): Self::Parsed) -> Answer {
+        memory
+            .corrupt(bytes)
+            .shortest_path()
+            .ok_or(format!("The first {bytes} bytes cut off the exit"))
+            .into()
+    }
+
+    fn part2((mut memory, bytes): Self::Parsed) -> Answer {
+        memory
+            .get_corrupt_coord(bytes, None)
+            .map(|(x, y)| format!("{x},{y}"))
+            .into()
+    }
+}
+
+impl Animate for Day18 {
+    fn animate((mut memory, bytes): Self::Parsed, part: u32) -> Animation {
+        let mut animation = Animation::new(PALETTE);
+        match part {
+            1 => memory.animate_bytes(bytes, &mut animation),
+            _ => {
+                if let Err(e) = memory.get_corrupt_coord(bytes, Some(&mut animation)) {
+                    animation.push_grid(e, &memory.grid);
+                }
+            }
+        }
+        animation
+    }
+}
+
+#[test]
+fn test_too_few_bytes() {
+    let lines = ["1,1".to_string()];
+    let err = Day18::parse(&lines).unwrap_err();
+    assert_eq!(
+        (err.line, err.message.as_str()),
+        (2, "Expected at least 12 bytes")
+    );
+
+    // Nothing in the way of the exit, however many bytes fall
+    let lines = vec!["0,6".to_string(); PRE_BYTES + 1];
+    let (mut memory, bytes) = Day18::parse(&lines).unwrap();
+    assert_eq!(
+        memory.get_corrupt_coord(bytes, None),
+        Err("The exit is still open after all 13 bytes".to_string())
+    );
+
+    // The first two bytes box in the start
+    let mut lines = vec!["1,0".to_string(), "0,1".to_string()];
+    lines.resize(PRE_BYTES + 1, "6,0".to_string());
+    let (mut memory, bytes) = Day18::parse(&lines).unwrap();
+    assert_eq!(
+        memory.get_corrupt_coord(bytes, None),
+        Err("The first 12 bytes already cut off the exit".to_string())
+    );
+    assert_eq!(
+        Day18::part1(Day18::parse(&lines).unwrap()),
+        Answer::Error("The first 12 bytes cut off the exit".to_string())
+    );
+}
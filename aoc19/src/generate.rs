@@ -0,0 +1,44 @@
+// A random set of towel patterns followed by `size` designs. About half of the designs are made
+// from the patterns, and the rest are random stripes that may or may not be possible. One color
+// never gets a single stripe towel, so not everything is possible.
+
+use aoc_common::Rng;
+
+const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+fn stripes(rng: &mut Rng, len: usize) -> String {
+    (0..len).map(|_| *rng.choose(&COLORS)).collect()
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let missing = *rng.choose(&COLORS);
+    let mut patterns: Vec<String> = COLORS
+        .iter()
+        .filter(|&&c| c != missing)
+        .map(|c| c.to_string())
+        .collect();
+    while patterns.len() < 100 {
+        let len = rng.below(7) + 2;
+        let pattern = stripes(rng, len);
+        if !patterns.contains(&pattern) {
+            patterns.push(pattern);
+        }
+    }
+    rng.shuffle(&mut patterns);
+
+    let mut lines = vec![patterns.join(", "), String::new()];
+    for _ in 0..size {
+        let len = rng.below(41) + 20;
+        let design = if rng.chance(0.5) {
+            let mut design = String::new();
+            while design.len() < len {
+                design.push_str(rng.choose(&patterns).as_str());
+            }
+            design
+        } else {
+            stripes(rng, len)
+        };
+        lines.push(design);
+    }
+    lines
+}
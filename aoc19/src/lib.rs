@@ -0,0 +1,128 @@
+//! Day 19: Linen Layout. Counts the ways towel patterns can make each design.
+
+pub mod generate;
+
+use aoc_common::{trace, Answer, Input, ParseError, Solution};
+use std::collections::HashMap;
+
+type LookupType<'a> = HashMap<&'a [u8], usize>;
+
+/// The available towel patterns and the designs to make from them.
+pub struct Onsen {
+    patterns: Vec<Vec<u8>>,
+    designs: Vec<Vec<u8>>,
+}
+
+impl Onsen {
+    /// Reads the comma separated patterns, a blank line, and then one design per line.
+    pub fn new(input: &Input) -> Result<Self, ParseError> {
+        let lines = input.lines();
+        let mut patterns = Vec::new();
+
+        let first = lines
+            .first()
+            .ok_or_else(|| ParseError::end_of_input(&lines, "Missing towel patterns"))?;
+        // The patterns are separated by ", ", so split on the commas and then drop the spaces
+        for (i, p) in first.split(|&b| b == b',').enumerate() {
+            let p = match i {
+                0 => p,
+                _ => p.strip_prefix(b" ").unwrap_or(p),
+            };
+            // An empty pattern would match forever without consuming anything
+            if p.is_empty() {
+                return Err(ParseError::new_bytes(0, first, p, "Empty towel pattern"));
+            }
+            patterns.push(p.to_vec());
+        }
+        match lines.get(1) {
+            Some(&[]) => {}
+            Some(line) => {
+                return Err(ParseError::new_bytes(
+                    1,
+                    line,
+                    line,
+                    "Expected a blank line",
+                ));
+            }
+            None => return Err(ParseError::end_of_input(&lines, "Missing designs")),
+        }
+        let designs = lines[2..].iter().map(|line| line.to_vec()).collect();
+        Ok(Onsen { patterns, designs })
+    }
+
+    // lookup is how we memoize our already seen results
+    fn check<'a>(&self, design: &'a [u8], lookup: &mut LookupType<'a>) -> usize {
+        trace!("Checking {}", String::from_utf8_lossy(design));
+        if design.is_empty() {
+            return 1;
+        }
+
+        if lookup.contains_key(design) {
+            return *lookup.get(design).unwrap();
+        }
+
+        let mut valid = 0;
+        for pattern in &self.patterns {
+            if design.starts_with(pattern) {
+                valid += self.check(&design[pattern.len()..], lookup);
+            }
+        }
+
+        lookup.insert(design, valid);
+        valid
+    }
+
+    /// Counts the designs that some patterns can make, or with `all`, every way of making each
+    /// design.
+    pub fn check_designs(&self, all: bool) -> usize {
+        let mut valid = 0;
+        let mut lookup: LookupType = HashMap::new();
+
+        for design in &self.designs {
+            let v = self.check(design, &mut lookup);
+            if all {
+                valid += v;
+            } else if v > 0 {
+                valid += 1;
+            }
+        }
+
+        valid
+    }
+}
+
+/// Day 19: Linen Layout.
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+
+    type Parsed = Onsen;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        Onsen::new(&Input::from_lines(lines))
+    }
+
+    fn parse_input(input: &Input) -> Result<Self::Parsed, ParseError> {
+        Onsen::new(input)
+    }
+
+    fn part1(parsed: Self::Parsed) -> Answer {
+        parsed.check_designs(false).into()
+    }
+
+    fn part2(parsed: Self::Parsed) -> Answer {
+        parsed.check_designs(true).into()
+    }
+}
+
+#[test]
+fn test_empty_pattern() {
+    let lines = ["r, , wr", "", "rwr"];
+    let lines = lines.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+    let err = Onsen::new(&Input::from_lines(&lines)).err().unwrap();
+    assert_eq!(
+        (err.line, err.column, err.message.as_str()),
+        (1, 4, "Empty towel pattern")
+    );
+}
@@ -0,0 +1,58 @@
+// A random racetrack on a `size` x `size` grid, which is bumped up to an odd size of at least 5.
+// Like the real input, it's a single winding path from S to E with walls everywhere else. Tracks
+// no bigger than the example's 15x15 are scored the same way the example is.
+
+use aoc_common::Rng;
+
+type Cell = (usize, usize);
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let side = size.max(5) | 1;
+    let cells = (side - 1) / 2;
+
+    // Grow a maze over the cells two tiles apart, keeping track of how each one was reached.
+    // The track is the path from the start to the deepest cell, which is long and winding,
+    // and the rest of the maze is left as walls so that there's only ever the one path.
+    let start = (rng.below(cells) * 2 + 1, rng.below(cells) * 2 + 1);
+    let mut parent: Vec<Vec<Option<Cell>>> = vec![vec![None; side]; side];
+    parent[start.1][start.0] = Some(start);
+    let mut stack = vec![start];
+    let mut deepest = start;
+    let mut max_depth = 0;
+    while let Some(&pos) = stack.last() {
+        let next: Vec<Cell> = [(0, -2), (2, 0), (0, 2), (-2, 0)]
+            .into_iter()
+            .filter_map(|(dx, dy)| {
+                let next = (pos.0.checked_add_signed(dx)?, pos.1.checked_add_signed(dy)?);
+                (next.0 < side - 1 && next.1 < side - 1 && parent[next.1][next.0].is_none())
+                    .then_some(next)
+            })
+            .collect();
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let step = *rng.choose(&next);
+        parent[step.1][step.0] = Some(pos);
+        stack.push(step);
+        if stack.len() > max_depth {
+            max_depth = stack.len();
+            deepest = step;
+        }
+    }
+
+    let mut grid = vec![vec!['#'; side]; side];
+    let mut pos = deepest;
+    grid[pos.1][pos.0] = 'E';
+    while pos != start {
+        let prev = parent[pos.1][pos.0].unwrap();
+        grid[(pos.1 + prev.1) / 2][(pos.0 + prev.0) / 2] = '.';
+        grid[prev.1][prev.0] = '.';
+        pos = prev;
+    }
+    grid[start.1][start.0] = 'S';
+
+    grid.into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect()
+}
@@ -0,0 +1,255 @@
+//! Day 20: Race Condition. Counts the cheats through walls that shorten a race.
+
+pub mod generate;
+
+#[cfg(test)]
+use aoc_common::{check, fixture, Rng};
+use aoc_common::{dijkstra, Answer, Coord, Grid, ParseError, Solution};
+
+// Part 1 cheats can pass through walls for 2 picoseconds, and part 2 for 20
+const SHORT_CHEAT: usize = 2;
+const LONG_CHEAT: usize = 20;
+
+/// The largest track that's treated as the example, which is 15x15 while the real input is
+/// 141x141.
+pub const PRE_DIM: usize = 15;
+// The example only has cheats that save a little, so it counts smaller savings than the real
+// input
+const PRE_SAVINGS: [usize; 2] = [2, 50];
+const SAVINGS: [usize; 2] = [100, 100];
+
+// Tiles that no path reaches
+const UNREACHED: usize = usize::MAX;
+
+// Parsing only lets through walls and track, so anything that isn't a wall is track
+fn is_track(grid: &Grid, pos: Coord) -> bool {
+    grid[pos] != '#'
+}
+
+fn possible_moves(grid: &Grid, pos: Coord) -> Vec<(Coord, usize)> {
+    grid.neighbors4(pos)
+        .filter(|&next| is_track(grid, next))
+        .map(|next| (next, 1))
+        .collect()
+}
+
+/// The racetrack, with how far every tile on it is from the start and from the end.
+#[derive(Debug)]
+pub struct Racetrack {
+    grid: Grid,
+    from_start: Grid<usize>,
+    from_end: Grid<usize>,
+    best: usize,
+}
+
+impl Racetrack {
+    /// Reads the track, which must have one `S` and one `E` with a path between them.
+    pub fn new(lines: &[String]) -> Result<Self, ParseError> {
+        let grid = Grid::from_lines(lines, None, |c| {
+            matches!(c, '#' | '.' | 'S' | 'E').then_some(c)
+        })?;
+        let start = grid
+            .find(&'S')
+            .ok_or_else(|| ParseError::end_of_input(lines, "Track has no start"))?;
+        let end = grid
+            .find(&'E')
+            .ok_or_else(|| ParseError::end_of_input(lines, "Track has no end"))?;
+
+        let from_start = Self::distances(&grid, start);
+        let from_end = Self::distances(&grid, end);
+        let best = from_start[end];
+        if best == UNREACHED {
+            return Err(ParseError::end_of_input(
+                lines,
+                "Track never reaches the end",
+            ));
+        }
+        Ok(Self {
+            grid,
+            from_start,
+            from_end,
+            best,
+        })
+    }
+
+    // The fewest steps from `from` to every tile, without cheating
+    fn distances(grid: &Grid, from: Coord) -> Grid<usize> {
+        let paths = dijkstra(from, |&pos| possible_moves(grid, pos));
+        let mut dist = Grid::new(grid.width(), grid.height(), UNREACHED);
+        for (&pos, &d) in &paths.dist {
+            dist[pos] = d;
+        }
+        dist
+    }
+
+    /// The picoseconds the race takes without cheating.
+    pub fn best_time(&self) -> usize {
+        self.best
+    }
+
+    // Every tile within `max` steps of `pos`, ignoring walls, along with how far away it is
+    fn cheat_ends(&self, pos: Coord, max: usize) -> impl Iterator<Item = (Coord, usize)> + '_ {
+        let max = max as isize;
+        (-max..=max).flat_map(move |dy| {
+            let reach = max - dy.abs();
+            (-reach..=reach).filter_map(move |dx| {
+                let end = (pos.0.checked_add_signed(dx)?, pos.1.checked_add_signed(dy)?);
+                self.grid
+                    .in_bounds(end)
+                    .then_some((end, dx.unsigned_abs() + dy.unsigned_abs()))
+            })
+        })
+    }
+
+    /// Counts the cheats that pass through walls for at most `max_cheat` picoseconds and save at
+    /// least `min_saving`. A cheat is only counted once for each start and end tile.
+    pub fn count_cheats(&self, max_cheat: usize, min_saving: usize) -> usize {
+        let mut count = 0;
+        for pos in self.grid.positions() {
+            let before = self.from_start[pos];
+            if before == UNREACHED {
+                continue;
+            }
+            for (end, steps) in self.cheat_ends(pos, max_cheat) {
+                let after = self.from_end[end];
+                if after == UNREACHED {
+                    continue;
+                }
+                let time = before + steps + after;
+                if time + min_saving <= self.best {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+}
+
+/// Day 20: Race Condition.
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+
+    type Parsed = (Racetrack, [usize; 2]);
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        let track = Racetrack::new(lines)?;
+        if track.grid.width() <= PRE_DIM {
+            Ok((track, PRE_SAVINGS))
+        } else {
+            Ok((track, SAVINGS))
+        }
+    }
+
+    fn part1((track, savings): Self::Parsed) -> Answer {
+        track.count_cheats(SHORT_CHEAT, savings[0]).into()
+    }
+
+    fn part2((track, savings): Self::Parsed) -> Answer {
+        track.count_cheats(LONG_CHEAT, savings[1]).into()
+    }
+}
+
+#[test]
+fn test_cheats() {
+    let track = Racetrack::new(&fixture!("prelim.txt")).unwrap();
+    assert_eq!(track.best_time(), 84);
+    assert_eq!(track.count_cheats(SHORT_CHEAT, 64), 1);
+    assert_eq!(track.count_cheats(SHORT_CHEAT, 40), 2);
+    assert_eq!(track.count_cheats(LONG_CHEAT, 76), 3);
+}
+
+// Counts the cheats a different way, for a track that's a single path like the puzzle's. The
+// path is walked from S to E, so each tile's time is its index, and every pair of tiles close
+// enough together is a cheat that saves the steps in between.
+#[cfg(test)]
+fn count_cheats_along_path(lines: &[String], max_cheat: usize, min_saving: usize) -> usize {
+    let grid = Grid::from_lines(lines, None, Some).unwrap();
+    let mut path = vec![grid.find(&'S').unwrap()];
+    while grid[path[path.len() - 1]] != 'E' {
+        let pos = path[path.len() - 1];
+        let back = path.len().checked_sub(2).map(|i| path[i]);
+        let next = grid
+            .neighbors4(pos)
+            .find(|&next| is_track(&grid, next) && Some(next) != back)
+            .unwrap();
+        path.push(next);
+    }
+
+    let mut count = 0;
+    for (i, a) in path.iter().enumerate() {
+        for (j, b) in path.iter().enumerate().skip(i + 1) {
+            let steps = a.0.abs_diff(b.0) + a.1.abs_diff(b.1);
+            if steps <= max_cheat && j - i >= steps + min_saving {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+#[test]
+fn test_full_size() {
+    // There's no real input checked in, so this is a generated track the same size as one (the
+    // same as inputs/generated/day20.txt)
+    let lines = generate::generate(&mut Rng::new(20), 141);
+    let (track, savings) = Day20::parse(&lines).unwrap();
+    assert_eq!(savings, SAVINGS);
+    for (cheat, saving) in [(SHORT_CHEAT, savings[0]), (LONG_CHEAT, savings[1])] {
+        let expected = count_cheats_along_path(&lines, cheat, saving);
+        assert_eq!(track.count_cheats(cheat, saving), expected);
+    }
+    assert_eq!(track.count_cheats(SHORT_CHEAT, savings[0]), 277);
+    assert_eq!(track.count_cheats(LONG_CHEAT, savings[1]), 199189);
+}
+
+#[test]
+fn test_cheats_random() {
+    // Small tracks, so that walking every pair of tiles stays quick
+    check(
+        50,
+        |rng| {
+            let size = rng.below(30);
+            let lines = generate::generate(rng, size);
+            // A cheat has to save something, or staying put would count as one
+            (lines, 1 + rng.below(LONG_CHEAT), 1 + rng.below(40))
+        },
+        |(lines, max_cheat, min_saving)| {
+            let mut smaller = Vec::new();
+            if *max_cheat > 1 {
+                smaller.push((lines.clone(), max_cheat - 1, *min_saving));
+            }
+            if *min_saving > 1 {
+                smaller.push((lines.clone(), *max_cheat, min_saving - 1));
+            }
+            smaller
+        },
+        |(lines, max_cheat, min_saving)| {
+            let track = Racetrack::new(lines).unwrap();
+            track.count_cheats(*max_cheat, *min_saving)
+                == count_cheats_along_path(lines, *max_cheat, *min_saving)
+        },
+    );
+}
+
+// Ignored until input.txt is downloaded
+#[test]
+#[ignore]
+fn test_input() {
+    let lines = fixture!("input.txt");
+    let (track, savings) = Day20::parse(&lines).unwrap();
+    assert_eq!(savings, SAVINGS);
+    for (cheat, saving) in [(SHORT_CHEAT, savings[0]), (LONG_CHEAT, savings[1])] {
+        let expected = count_cheats_along_path(&lines, cheat, saving);
+        assert_eq!(track.count_cheats(cheat, saving), expected);
+    }
+}
+
+#[test]
+fn test_no_end() {
+    let lines = ["#####", "#S#.#", "#####"];
+    let lines = lines.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+    let err = Racetrack::new(&lines).unwrap_err();
+    assert_eq!((err.line, err.message.as_str()), (4, "Track has no end"));
+}
@@ -0,0 +1,10 @@
+use aoc20::Day20;
+use aoc_common::run_day;
+
+fn main() {
+    run_day::<Day20>(
+        env!("CARGO_MANIFEST_DIR"),
+        &["prelim.txt", "input.txt"],
+        &["prelim.txt", "input.txt"],
+    );
+}
@@ -0,0 +1,9 @@
+// `size` random door codes, each three digits followed by `A`.
+
+use aoc_common::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| format!("{:03}A", rng.below(1000)))
+        .collect()
+}
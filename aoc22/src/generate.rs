@@ -0,0 +1,9 @@
+// `size` random initial secret numbers, anywhere in the 24 bit range the secrets live in.
+
+use aoc_common::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| rng.range(1..1 << 24).to_string())
+        .collect()
+}
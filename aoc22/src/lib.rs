@@ -0,0 +1,323 @@
+//! Day 22: Monkey Market. Simulates buyers' secret numbers to find the best time to sell.
+
+pub mod generate;
+
+#[cfg(test)]
+use aoc_common::{check, fixture, shrink_vec};
+use aoc_common::{par_map, parse_token, Answer, ParseError, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+const MAX_STEPS: usize = 2000 + 1;
+
+type Secrets = [u64; MAX_STEPS];
+type Price = [i64; MAX_STEPS];
+type Diff = [i64; MAX_STEPS];
+
+type Quad = (i8, i8, i8, i8);
+type QuadMap = HashMap<Quad, i64>;
+
+// A single buyer, along with the price at the first sighting of each quad of price changes
+struct Bidder {
+    secrets: Secrets,
+    prices: Price,
+    diffs: Diff,
+    first_prices: Vec<(Quad, i64)>,
+}
+
+impl Bidder {
+    fn new(start_secret: u64) -> Self {
+        let mut secrets: Secrets = [0; MAX_STEPS];
+        let mut prices: Price = [0; MAX_STEPS];
+        let mut diffs: Diff = [0; MAX_STEPS];
+        let mut first_prices = Vec::new();
+        let mut last_price = (start_secret % 10) as i64;
+        let mut secret = start_secret;
+
+        let mut seen_set = HashSet::new();
+
+        for i in 0..MAX_STEPS {
+            secrets[i] = secret;
+            prices[i] = (secret % 10) as i64;
+            diffs[i] = prices[i] - last_price;
+            last_price = prices[i];
+
+            // We use i > 3, because technically the first price doesn't
+            // really have a difference to it.
+            if i > 3 {
+                let quad = (
+                    diffs[i - 3] as i8,
+                    diffs[i - 2] as i8,
+                    diffs[i - 1] as i8,
+                    diffs[i] as i8,
+                );
+                if seen_set.insert(quad) {
+                    first_prices.push((quad, prices[i]));
+                }
+            }
+            secret = compute_secret(secret);
+        }
+
+        Bidder {
+            secrets,
+            prices,
+            diffs,
+            first_prices,
+        }
+    }
+}
+
+/// The secret numbers, prices and price changes of every buyer.
+#[derive(Debug)]
+pub struct BananaMarket {
+    secrets: Vec<Secrets>,
+    prices: Vec<Price>,
+    diffs: Vec<Diff>,
+    quad_map: QuadMap,
+}
+
+impl BananaMarket {
+    fn new() -> Self {
+        BananaMarket {
+            secrets: Vec::new(),
+            prices: Vec::new(),
+            diffs: Vec::new(),
+            quad_map: HashMap::new(),
+        }
+    }
+
+    /// Creates a market from each buyer's starting secret number.
+    pub fn from_vec(start_secrets: &[u64]) -> Self {
+        // Buyers are independent, so only adding them to the market needs to be done in order
+        let mut market = BananaMarket::new();
+        for bidder in par_map(start_secrets, |&secret| Bidder::new(secret)) {
+            market.add_bidder(bidder);
+        }
+
+        market
+    }
+
+    fn add_bidder(&mut self, bidder: Bidder) {
+        for (quad, price) in bidder.first_prices {
+            *self.quad_map.entry(quad).or_insert(0) += price;
+        }
+        self.secrets.push(bidder.secrets);
+        self.prices.push(bidder.prices);
+        self.diffs.push(bidder.diffs);
+    }
+
+    // Enumerate all possible quads of value changes
+    #[allow(dead_code)]
+    fn find_diff_quads(&self) -> HashSet<(i64, i64, i64, i64)> {
+        let mut quads = HashSet::new();
+
+        for diff in &self.diffs {
+            // Skip the first diff, which isn't a real change, the same as Bidder::new does
+            for i in 1..MAX_STEPS - 3 {
+                let a = diff[i];
+                let b = diff[i + 1];
+                let c = diff[i + 2];
+                let d = diff[i + 3];
+                let quad = (a, b, c, d);
+                quads.insert(quad);
+            }
+        }
+
+        quads
+    }
+
+    // Find the first instance of the diff quad for a particular bidder and return their final price
+    #[allow(dead_code)]
+    fn evaluate_single_diff_quad(&self, num: usize, diff_quad: &(i64, i64, i64, i64)) -> i64 {
+        let diff = &self.diffs[num];
+        let prices = &self.prices[num];
+
+        for i in 1..MAX_STEPS - 3 {
+            if diff[i] == diff_quad.0
+                && diff[i + 1] == diff_quad.1
+                && diff[i + 2] == diff_quad.2
+                && diff[i + 3] == diff_quad.3
+            {
+                return prices[i + 3];
+            }
+        }
+
+        0
+    }
+
+    #[allow(dead_code)]
+    fn evaluate_diff_quad(&self, diff_quad: &(i64, i64, i64, i64)) -> i64 {
+        let mut total = 0;
+        let num_bidders = self.prices.len();
+
+        for i in 0..num_bidders {
+            total += self.evaluate_single_diff_quad(i, diff_quad);
+        }
+
+        total
+    }
+
+    /// Returns the most bananas any sequence of four price changes can buy.
+    pub fn find_best_total_lookup(&self) -> i64 {
+        let mut best = 0;
+        for &v in self.quad_map.values() {
+            if v > best {
+                best = v;
+            }
+        }
+
+        best
+    }
+
+    #[allow(dead_code)]
+    fn find_best_total_brute_force(&self) -> i64 {
+        let mut best = 0;
+
+        let quads = self.find_diff_quads();
+        for quad in quads {
+            let val = self.evaluate_diff_quad(&quad);
+            if val > best {
+                best = val;
+            }
+        }
+
+        best
+    }
+}
+
+/// Reads one starting secret number per line.
+pub fn read_secret_numbers(lines: &[String]) -> Result<Vec<u64>, ParseError> {
+    let mut result = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let v = parse_token(i, line, line)?;
+        result.push(v);
+    }
+
+    Ok(result)
+}
+
+fn mix(secret: u64, mix_val: u64) -> u64 {
+    secret ^ mix_val
+}
+
+fn prune(secret: u64) -> u64 {
+    secret % 16777216
+}
+
+/// Returns the next secret number in the sequence.
+pub fn compute_secret(val: u64) -> u64 {
+    let mut result = val;
+    let mul_res = result * 64; // Multiply by 64
+    result = mix(result, mul_res); // Mix
+    result = prune(result); // Prune
+
+    let div_res = result / 32; // Divide by 32
+    result = mix(result, div_res); // Mix
+    result = prune(result); // Prune
+
+    let mul_res = result * 2048; // Multiply by 2048
+    result = mix(result, mul_res); // Mix
+    result = prune(result); // Prune
+
+    result
+}
+
+/// Returns the secret number `n` steps on from `val`.
+pub fn compute_secret_n(val: u64, n: u64) -> u64 {
+    let mut secret = val;
+    for _ in 0..n {
+        secret = compute_secret(secret);
+    }
+    secret
+}
+
+/// Sums the secret number `n` steps on from each of `secrets`.
+pub fn compute_secret_n_sum(secrets: &[u64], n: u64) -> u64 {
+    let mut sum = 0;
+
+    for secret in secrets {
+        let new_secret = compute_secret_n(*secret, n);
+        sum += new_secret;
+    }
+
+    sum
+}
+
+/// Day 22: Monkey Market.
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+
+    type Parsed = Vec<u64>;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        read_secret_numbers(lines)
+    }
+
+    fn part1(parsed: Self::Parsed) -> Answer {
+        compute_secret_n_sum(&parsed, 2000).into()
+    }
+
+    fn part2(parsed: Self::Parsed) -> Answer {
+        BananaMarket::from_vec(&parsed)
+            .find_best_total_lookup()
+            .into()
+    }
+}
+
+#[test]
+fn test_brute_force() {
+    let secrets = read_secret_numbers(&fixture!("prelim2.txt")).unwrap();
+    let total = BananaMarket::from_vec(&secrets).find_best_total_brute_force();
+    assert_eq!(
+        total,
+        BananaMarket::from_vec(&secrets).find_best_total_lookup()
+    );
+}
+
+#[test]
+fn test_brute_force_random() {
+    // The brute force is quadratic in the number of bidders, so keep the markets small
+    check(
+        10,
+        |rng| {
+            (0..1 + rng.below(3))
+                .map(|_| rng.below(1 << 24) as u64)
+                .collect::<Vec<_>>()
+        },
+        |secrets| {
+            let mut smaller = shrink_vec(secrets);
+            for i in 0..secrets.len() {
+                let mut halved = secrets.clone();
+                halved[i] /= 2;
+                if halved[i] != secrets[i] {
+                    smaller.push(halved);
+                }
+            }
+            smaller
+        },
+        |secrets| {
+            let market = BananaMarket::from_vec(secrets);
+            market.find_best_total_brute_force() == market.find_best_total_lookup()
+        },
+    );
+}
+
+#[test]
+fn test_compute_secret_10() {
+    let mut secrets = Vec::new();
+    secrets.push(123);
+    for i in 0..10 {
+        let secret = secrets[i];
+        let new_secret = compute_secret(secret);
+        secrets.push(new_secret);
+    }
+
+    let expect_vec = vec![
+        123, 15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432,
+        5908254,
+    ];
+
+    assert_eq!(secrets, expect_vec);
+}
@@ -0,0 +1,51 @@
+// A random network of `size` computers with a LAN party hidden in it. The party is a clique of up
+// to 13 computers, where each member has one more connection outside of it. Everyone else has
+// too few connections to be part of a clique that big, so the party is the only answer.
+
+use aoc_common::Rng;
+use std::collections::BTreeSet;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut names: Vec<String> = (b'a'..=b'z')
+        .flat_map(|a| (b'a'..=b'z').map(move |b| format!("{}{}", a as char, b as char)))
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(size.clamp(4, names.len()));
+
+    // The first `party` names are the party, and the rest are outsiders
+    let party = (names.len() / 3).clamp(3, 13);
+    let mut edges = BTreeSet::new();
+    for a in 0..party {
+        for b in a + 1..party {
+            edges.insert((a, b));
+        }
+        // Each outsider knows at most one member of the party
+        if party + a < names.len() {
+            edges.insert((a, party + a));
+        }
+    }
+
+    // Connections between outsiders are capped, so they can't form a party-sized clique
+    let outsiders = names.len() - party;
+    let mut degree = vec![0; outsiders];
+    for _ in 0..outsiders * party {
+        let (a, b) = (rng.below(outsiders), rng.below(outsiders));
+        if a == b || degree[a] >= party - 2 || degree[b] >= party - 2 {
+            continue;
+        }
+        if edges.insert((party + a.min(b), party + a.max(b))) {
+            degree[a] += 1;
+            degree[b] += 1;
+        }
+    }
+
+    let mut lines: Vec<String> = edges
+        .into_iter()
+        .map(|(a, b)| match rng.chance(0.5) {
+            true => format!("{}-{}", names[a], names[b]),
+            false => format!("{}-{}", names[b], names[a]),
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    lines
+}
@@ -0,0 +1,148 @@
+//! Day 23: LAN Party. Finds groups of fully connected computers.
+
+pub mod generate;
+
+use aoc_common::{par_map, trace, Answer, ParseError, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// Reads `a-b` connections into a map from each computer to its neighbors.
+pub fn read_network_map(lines: &[String]) -> Result<HashMap<String, Vec<String>>, ParseError> {
+    let mut map: HashMap<String, Vec<String>> = HashMap::new();
+    for (i, line) in lines.iter().enumerate() {
+        let toks: Vec<&str> = line.split('-').collect();
+        if toks.len() != 2 || toks.iter().any(|t| t.is_empty()) {
+            return Err(ParseError::new(
+                i,
+                line,
+                line,
+                "Expected a connection like `kh-tc`",
+            ));
+        }
+        let computer1 = toks[0].to_string();
+        let computer2 = toks[1].to_string();
+        if let std::collections::hash_map::Entry::Vacant(e) = map.entry(computer1.clone()) {
+            e.insert(vec![computer2.clone()]);
+        } else {
+            map.get_mut(&computer1).unwrap().push(computer2.clone());
+        }
+        if let std::collections::hash_map::Entry::Vacant(e) = map.entry(computer2.clone()) {
+            e.insert(vec![computer1.clone()]);
+        } else {
+            map.get_mut(&computer2).unwrap().push(computer1.clone());
+        }
+    }
+    trace!("{map:?}");
+    Ok(map)
+}
+
+/// Counts sets of three connected computers where one name starts with `t`.
+pub fn find_trios(map: &HashMap<String, Vec<String>>) -> usize {
+    let mut set: HashSet<(&String, &String, &String)> = HashSet::new();
+    let mut labels = map.keys().collect::<Vec<&String>>();
+    labels.sort();
+    for label_1 in labels {
+        let first_values = map.get(label_1).unwrap();
+        for label_2 in first_values {
+            if label_2 != label_1 {
+                let second_values = map.get(label_2).unwrap();
+                for label_3 in second_values {
+                    let third_values = map.get(label_3).unwrap();
+                    if third_values.contains(label_1) {
+                        let mut val_vec = [label_1, label_2, label_3];
+                        val_vec.sort();
+                        set.insert((val_vec[0], val_vec[1], val_vec[2]));
+                        trace!("Found trio: {label_1} {label_2} {label_3}");
+                    }
+                }
+            }
+        }
+    }
+    let trios = set
+        .iter()
+        .filter(|x| x.0.starts_with('t') || x.1.starts_with('t') || x.2.starts_with('t'))
+        .collect::<Vec<_>>()
+        .len();
+    trios
+}
+
+fn get_combos(map: &HashMap<String, Vec<String>>, start: &str) -> Vec<Vec<String>> {
+    let mut combos = Vec::new();
+    let connections = map.get(start).unwrap();
+    let c_len = connections.len() as u32;
+
+    let total = 2u64.pow(c_len);
+    for i in 0..total {
+        let mut new_vec = Vec::new();
+        new_vec.push(start.to_string());
+        for j in 0..c_len {
+            let p = 1 << j;
+            if i & p != 0 {
+                new_vec.push(connections[j as usize].clone());
+            }
+        }
+        new_vec.sort(); // Always sort to keep it easier to check what we've tried already
+        combos.push(new_vec);
+    }
+    combos
+}
+
+fn check_clique(map: &HashMap<String, Vec<String>>, clique: &[String]) -> bool {
+    for m in clique {
+        let connections = map.get(m).unwrap();
+        for n in clique {
+            if m != n && !connections.contains(n) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+// The largest clique whose alphabetically first member is `key`. Every clique is found from
+// exactly one key this way, so keys can be searched independently of each other.
+fn find_clique_from(map: &HashMap<String, Vec<String>>, key: &String) -> Vec<String> {
+    let mut max_clique = Vec::new();
+    for clique in get_combos(map, key) {
+        if clique[0] == *key && clique.len() > max_clique.len() && check_clique(map, &clique) {
+            max_clique = clique;
+        }
+    }
+    max_clique
+}
+
+/// Returns the largest fully connected set of computers, sorted and joined with commas.
+pub fn find_cliques(map: &HashMap<String, Vec<String>>) -> String {
+    // Sorted, so that ties always go to the same clique
+    let mut keys = map.keys().collect::<Vec<_>>();
+    keys.sort();
+    let mut max_clique = Vec::new();
+    for clique in par_map(&keys, |key| find_clique_from(map, key)) {
+        if clique.len() > max_clique.len() {
+            max_clique = clique;
+        }
+    }
+
+    max_clique.join(",")
+}
+
+/// Day 23: LAN Party.
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+
+    type Parsed = HashMap<String, Vec<String>>;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        read_network_map(lines)
+    }
+
+    fn part1(parsed: Self::Parsed) -> Answer {
+        find_trios(&parsed).into()
+    }
+
+    fn part2(parsed: Self::Parsed) -> Answer {
+        find_cliques(&parsed).into()
+    }
+}
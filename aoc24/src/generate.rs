@@ -0,0 +1,148 @@
+// A random ripple carry adder for `size` bit numbers (at least 16, and at most `MAX_BITS` so that
+// the circuit still parses), with four pairs of gate outputs swapped like the real input. Each swap is one of the kinds of mistake that
+// `find_swaps` looks for, and they're kept a few bits apart so they don't overlap.
+
+use crate::Value;
+use aoc_common::Rng;
+use std::collections::HashSet;
+
+// The sum has one more bit than the numbers, and it has to fit in a `Value`. Wire numbers are
+// also kept to 2 digits.
+const MAX_BITS: usize = if Value::BITS < 100 {
+    Value::BITS as usize - 1
+} else {
+    99
+};
+
+#[derive(Default)]
+struct Adder {
+    gates: Vec<(String, &'static str, String, String)>,
+    // The index of each kind of gate for every bit, so that swaps can find them. Bit 0 is a half
+    // adder, so it only has a sum and a carry out.
+    sum: Vec<usize>,
+    half: Vec<usize>,
+    bit_and: Vec<usize>,
+    carry_and: Vec<usize>,
+    carry_out: Vec<usize>,
+}
+
+impl Adder {
+    fn add(&mut self, a: &str, op: &'static str, b: &str, out: &str) -> usize {
+        self.gates
+            .push((a.to_string(), op, b.to_string(), out.to_string()));
+        self.gates.len() - 1
+    }
+}
+
+fn random_name(rng: &mut Rng, used: &mut HashSet<String>) -> String {
+    loop {
+        // Names starting with x, y or z would look like inputs and outputs
+        let first = char::from(b'a' + rng.below(23) as u8);
+        let rest: String = (0..2)
+            .map(|_| char::from(b'a' + rng.below(26) as u8))
+            .collect();
+        let name = format!("{first}{rest}");
+        if used.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+fn build_adder(rng: &mut Rng, bits: usize) -> Adder {
+    let mut used = HashSet::new();
+    let mut adder = Adder::default();
+
+    let mut carry = random_name(rng, &mut used);
+    let sum = adder.add("x00", "XOR", "y00", "z00");
+    let carry_out = adder.add("x00", "AND", "y00", &carry);
+    adder.sum.push(sum);
+    adder.carry_out.push(carry_out);
+    adder.half.push(usize::MAX);
+    adder.bit_and.push(usize::MAX);
+    adder.carry_and.push(usize::MAX);
+
+    for i in 1..bits {
+        let (x, y, z) = (format!("x{i:02}"), format!("y{i:02}"), format!("z{i:02}"));
+        let half = random_name(rng, &mut used);
+        let bit_and = random_name(rng, &mut used);
+        let carry_and = random_name(rng, &mut used);
+        // The last carry out is the extra top bit of the sum
+        let next_carry = match i + 1 == bits {
+            true => format!("z{bits:02}"),
+            false => random_name(rng, &mut used),
+        };
+
+        let gates = [
+            adder.add(&x, "XOR", &y, &half),
+            adder.add(&x, "AND", &y, &bit_and),
+            adder.add(&half, "XOR", &carry, &z),
+            adder.add(&half, "AND", &carry, &carry_and),
+            adder.add(&bit_and, "OR", &carry_and, &next_carry),
+        ];
+        adder.half.push(gates[0]);
+        adder.bit_and.push(gates[1]);
+        adder.sum.push(gates[2]);
+        adder.carry_and.push(gates[3]);
+        adder.carry_out.push(gates[4]);
+        carry = next_carry;
+    }
+    adder
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    generate_with_swaps(rng, size).0
+}
+
+// Also returns the names of the swapped outputs, which is the answer to part 2
+fn generate_with_swaps(rng: &mut Rng, size: usize) -> (Vec<String>, Vec<String>) {
+    let bits = size.clamp(16, MAX_BITS);
+    let mut adder = build_adder(rng, bits);
+
+    // One swap in each quarter of the adder, at least 3 bits apart and staying clear of both ends
+    let quarter = (bits - 4) / 4;
+    let swap_bits: Vec<usize> = (0..4)
+        .map(|q| 2 + q * quarter + rng.below(quarter - 2))
+        .collect();
+    let mut swapped = Vec::new();
+    for bit in swap_bits {
+        let (a, b) = match rng.below(3) {
+            0 => (adder.sum[bit], adder.carry_out[bit]),
+            1 => (adder.sum[bit], adder.carry_and[bit]),
+            _ => (adder.half[bit], adder.bit_and[bit]),
+        };
+        let out_a = adder.gates[a].3.clone();
+        adder.gates[a].3 = std::mem::replace(&mut adder.gates[b].3, out_a);
+        swapped.push(adder.gates[a].3.clone());
+        swapped.push(adder.gates[b].3.clone());
+    }
+
+    let mut lines = Vec::new();
+    for var in ["x", "y"] {
+        for i in 0..bits {
+            lines.push(format!("{var}{i:02}: {}", rng.below(2)));
+        }
+    }
+    lines.push(String::new());
+
+    rng.shuffle(&mut adder.gates);
+    for (a, op, b, out) in adder.gates {
+        let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+        lines.push(format!("{a} {op} {b} -> {out}"));
+    }
+    (lines, swapped)
+}
+
+#[test]
+fn test_find_generated_swaps() {
+    // The widest adder that can be generated is included
+    for seed in 0..6 {
+        let size = match seed {
+            5 => usize::MAX,
+            _ => 16 + seed as usize * 10,
+        };
+        let (lines, mut swapped) = generate_with_swaps(&mut Rng::new(seed), size);
+        swapped.sort();
+        let circuit = super::read_circuit(&lines).unwrap();
+        assert_eq!(circuit.find_swaps(), swapped.join(","));
+    }
+}
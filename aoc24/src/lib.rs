@@ -0,0 +1,596 @@
+//! Day 24: Crossed Wires. Simulates a circuit of logic gates and finds its swapped outputs.
+
+pub mod generate;
+
+#[cfg(test)]
+use aoc_common::{check, shrink_vec};
+use aoc_common::{debug, log_enabled, trace, Answer, Level, ParseError, Solution};
+use std::collections::HashMap;
+
+// The numbers the circuit adds, where each `z` wire is one bit. The `checked` feature makes
+// these 128 bits, for circuits wider than 64.
+#[cfg(feature = "checked")]
+pub type Value = u128;
+#[cfg(not(feature = "checked"))]
+pub type Value = u64;
+
+#[derive(Clone, Debug, PartialEq)]
+enum GateOp {
+    And,
+    Or,
+    Xor,
+}
+
+#[derive(Clone, Debug)]
+struct Gate {
+    gate_op: GateOp,
+    operand_1: String,
+    operand_2: String,
+}
+
+impl Gate {
+    fn new(
+        i: usize,
+        line: &str,
+        op: &str,
+        operand_1: String,
+        operand_2: String,
+    ) -> Result<Self, ParseError> {
+        let gate_op = match op {
+            "AND" => GateOp::And,
+            "OR" => GateOp::Or,
+            "XOR" => GateOp::Xor,
+            _ => return Err(ParseError::new(i, line, op, "Invalid op")),
+        };
+        Ok(Self {
+            gate_op,
+            operand_1,
+            operand_2,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum WireState {
+    True,
+    False,
+    Gated,
+}
+
+#[derive(Clone, Debug)]
+struct Wire {
+    name: String,
+    state: WireState,
+    gate: Option<Gate>,
+}
+
+impl Wire {
+    fn new(i: usize, line: &str) -> Result<Self, ParseError> {
+        if line.is_empty() {
+            return Err(ParseError::new(i, line, line, "Invalid wire line"));
+        }
+        if line.contains(':') {
+            Self::new_init_wire(i, line)
+        } else {
+            Self::new_gate_wire(i, line)
+        }
+    }
+
+    // Output wires are numbered, since they make up the bits of the result
+    fn check_name(i: usize, line: &str, name: &str) -> Result<(), ParseError> {
+        let bit = match name.strip_prefix('z') {
+            Some(bit) => bit.parse::<u32>().ok(),
+            None => Some(0),
+        };
+        if name.is_empty() || bit.is_none() {
+            return Err(ParseError::new(i, line, name, "Invalid wire name"));
+        }
+        if bit.is_some_and(|bit| bit >= Value::BITS) {
+            return Err(ParseError::new(i, line, name, "Too many bits"));
+        }
+        Ok(())
+    }
+
+    fn new_init_wire(i: usize, line: &str) -> Result<Self, ParseError> {
+        // x00: 1
+        let toks = line.split(':').collect::<Vec<_>>();
+        if toks.len() != 2 {
+            return Err(ParseError::new(i, line, line, "Expected `name: value`"));
+        }
+        Self::check_name(i, line, toks[0])?;
+        let name = toks[0].to_string();
+        let state = match toks[1].trim() {
+            "1" => WireState::True,
+            "0" => WireState::False,
+            other => return Err(ParseError::new(i, line, other, "Invalid state")),
+        };
+        Ok(Self {
+            name,
+            state,
+            gate: None,
+        })
+    }
+
+    fn new_gate_wire(i: usize, line: &str) -> Result<Self, ParseError> {
+        // x00 AND y00 -> z00
+        let toks = line.split_ascii_whitespace().collect::<Vec<_>>();
+        if toks.len() != 5 || toks[3] != "->" {
+            return Err(ParseError::new(i, line, line, "Expected `a OP b -> c`"));
+        }
+        Self::check_name(i, line, toks[4])?;
+        let operand_1 = toks[0].to_string();
+        let operand_2 = toks[2].to_string();
+        let name = toks[4].to_string();
+        let gate = Some(Gate::new(i, line, toks[1], operand_1, operand_2)?);
+        Ok(Self {
+            name,
+            state: WireState::Gated,
+            gate,
+        })
+    }
+}
+
+/// The wires of the adder, each either an initial value or the output of a gate.
+#[derive(Debug)]
+pub struct Circuit {
+    wires: HashMap<String, Wire>,
+    max_z: u32,
+}
+
+impl Circuit {
+    fn new() -> Circuit {
+        Circuit {
+            wires: HashMap::new(),
+            max_z: 0,
+        }
+    }
+
+    fn add_wire(&mut self, wire: &Wire) {
+        if wire.name.starts_with("z") {
+            let val = wire.name[1..].parse().unwrap();
+            if val > self.max_z {
+                self.max_z = val;
+            }
+        }
+        self.wires.insert(wire.name.clone(), wire.clone());
+    }
+
+    fn resolve_wire_no_mut(&self, name: &str) -> WireState {
+        let wire = self.wires.get(name).unwrap().clone();
+        let state = wire.state;
+        match state {
+            WireState::True | WireState::False => state,
+            WireState::Gated => {
+                let gate = wire.gate.as_ref().unwrap();
+                let operand_1 = self.resolve_wire_no_mut(&gate.operand_1);
+                assert!(operand_1 == WireState::True || operand_1 == WireState::False);
+                let operand_2 = self.resolve_wire_no_mut(&gate.operand_2);
+                assert!(operand_2 == WireState::True || operand_2 == WireState::False);
+                let gate_op = &gate.gate_op;
+
+                match gate_op {
+                    GateOp::And => {
+                        if operand_1 == WireState::True && operand_2 == WireState::True {
+                            WireState::True
+                        } else {
+                            WireState::False
+                        }
+                    }
+                    GateOp::Or => {
+                        if operand_1 == WireState::True || operand_2 == WireState::True {
+                            WireState::True
+                        } else {
+                            WireState::False
+                        }
+                    }
+                    GateOp::Xor => {
+                        if operand_1 != operand_2 {
+                            WireState::True
+                        } else {
+                            WireState::False
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn resolve_wire(&mut self, name: &str) -> WireState {
+        let wire = self.wires.get(name).unwrap().clone();
+        let state = wire.state;
+        match state {
+            WireState::True | WireState::False => state,
+            WireState::Gated => {
+                let gate = wire.gate.as_ref().unwrap();
+                let operand_1 = self.resolve_wire(&gate.operand_1);
+                assert!(operand_1 == WireState::True || operand_1 == WireState::False);
+                let operand_2 = self.resolve_wire(&gate.operand_2);
+                assert!(operand_2 == WireState::True || operand_2 == WireState::False);
+                let gate_op = &gate.gate_op;
+                let result = match gate_op {
+                    GateOp::And => {
+                        if operand_1 == WireState::True && operand_2 == WireState::True {
+                            WireState::True
+                        } else {
+                            WireState::False
+                        }
+                    }
+                    GateOp::Or => {
+                        if operand_1 == WireState::True || operand_2 == WireState::True {
+                            WireState::True
+                        } else {
+                            WireState::False
+                        }
+                    }
+                    GateOp::Xor => {
+                        if operand_1 != operand_2 {
+                            WireState::True
+                        } else {
+                            WireState::False
+                        }
+                    }
+                };
+                let wire = Wire {
+                    name: name.to_string(),
+                    state: result,
+                    gate: None,
+                };
+                self.wires.insert(name.to_string(), wire);
+                result
+            }
+        }
+    }
+
+    fn get_value_from_bit_vector(&self, prefix: &str) -> Value {
+        let mut zvec = self
+            .wires
+            .keys()
+            .filter(|&n| n.starts_with(prefix))
+            .collect::<Vec<_>>();
+        zvec.sort();
+        zvec.reverse();
+        let mut result = 0;
+        for &name in zvec.iter() {
+            result <<= 1;
+            if self.wires.get(name).unwrap().state == WireState::True {
+                result += 1;
+            }
+        }
+        result
+    }
+
+    /// Resolves every wire, returning the number on the `z` wires.
+    pub fn simulate(&mut self) -> Value {
+        let mut names = Vec::new();
+        for (name, _) in self.wires.iter() {
+            names.push(name.clone());
+        }
+
+        for name in names {
+            self.resolve_wire(&name);
+        }
+
+        trace!("{self:#?}");
+        self.get_value_from_bit_vector("z")
+    }
+
+    fn simulate_no_mut(&self) -> Value {
+        let mut names = Vec::new();
+        for (name, _) in self.wires.iter() {
+            names.push(name.clone());
+        }
+        let mut result = 0;
+
+        for (name, _) in self.wires.iter() {
+            if let Some(num) = name.strip_prefix('z') {
+                let num = num.parse::<u32>().unwrap();
+                let bit_result = self.resolve_wire_no_mut(name);
+                if bit_result == WireState::True {
+                    result |= 1 << num;
+                }
+            }
+        }
+
+        trace!("{self:#?}");
+        result
+    }
+
+    // Returns true if this is x_num ^ y_num
+    fn is_xy_xor(&self, num: u32, wire: &str) -> bool {
+        match &self.wires.get(wire).unwrap().gate {
+            None => {
+                return false;
+            }
+            Some(vz_zz) => {
+                let gate = &vz_zz.gate_op;
+                if *gate != GateOp::Xor {
+                    return false;
+                }
+                let x_zz = format!("x{:02}", num);
+                let y_zz = format!("y{:02}", num);
+                let lhs = &vz_zz.operand_1;
+                let rhs = &vz_zz.operand_2;
+                if (*lhs == x_zz && *rhs == y_zz) || (*lhs == y_zz && *rhs == x_zz) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    // Returns true if this is x_num && y_num
+    fn is_xy_and(&self, num: u32, wire: &str) -> bool {
+        match &self.wires.get(wire).unwrap().gate {
+            None => {
+                return false;
+            }
+            Some(vz_zz) => {
+                let gate = &vz_zz.gate_op;
+                if *gate != GateOp::And {
+                    return false;
+                }
+                let x_zz = format!("x{:02}", num);
+                let y_zz = format!("y{:02}", num);
+                let lhs = &vz_zz.operand_1;
+                let rhs = &vz_zz.operand_2;
+                if (*lhs == x_zz && *rhs == y_zz) || (*lhs == y_zz && *rhs == x_zz) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    // Returns true if this is x_num && y_num
+    fn is_non_xy_and(&self, _num: u32, wire: &str) -> bool {
+        if wire.starts_with('z') {
+            // IDK if this case actually happens.
+            trace!("non-xy check looks at z wire: {wire}");
+            return false;
+        }
+        match &self.wires.get(wire).unwrap().gate {
+            None => false,
+            Some(vz_zz) => {
+                let gate = &vz_zz.gate_op;
+                if *gate != GateOp::And {
+                    return false;
+                }
+                let lhs = &vz_zz.operand_1;
+                let rhs = &vz_zz.operand_2;
+                // We don't need to recurse; instead we just check that the operands aren't x/y.
+                if lhs.starts_with('x')
+                    || lhs.starts_with('y')
+                    || rhs.starts_with('x')
+                    || rhs.starts_with('y')
+                {
+                    return false;
+                }
+                true
+            }
+        }
+    }
+
+    // Returns false if carry chain isn't valid
+    fn is_valid_carry_chain(&self, num: u32, wire: &str, invalid: &mut Vec<String>) -> bool {
+        match &self.wires.get(wire).unwrap().gate {
+            None => {
+                invalid.push(wire.to_string());
+                false
+            }
+            Some(vwire) => {
+                let gate = &vwire.gate_op;
+                if *gate != GateOp::Or {
+                    if num == 1 && *gate == GateOp::And {
+                        // TODO: We can do better here, but I'm bored. LOL
+                        return true;
+                    }
+                    invalid.push(wire.to_string());
+                    return false;
+                }
+
+                let lhs = &vwire.operand_1;
+                let rhs = &vwire.operand_2;
+                let valid_xy_and_lhs = self.is_xy_and(num - 1, lhs);
+                let valid_xy_and_rhs = self.is_xy_and(num - 1, rhs);
+                let valid_non_xy_and_lhs = self.is_non_xy_and(num, lhs);
+                let valid_non_xy_and_rhs = self.is_non_xy_and(num, rhs);
+
+                if valid_xy_and_lhs && valid_non_xy_and_rhs
+                    || valid_xy_and_rhs && valid_non_xy_and_lhs
+                {
+                    return true;
+                }
+
+                if valid_xy_and_lhs {
+                    invalid.push(rhs.clone());
+                    return false;
+                }
+                if valid_xy_and_rhs {
+                    invalid.push(lhs.clone());
+                    return false;
+                }
+                if valid_non_xy_and_lhs {
+                    invalid.push(rhs.clone());
+                    return false;
+                }
+                if valid_non_xy_and_rhs {
+                    invalid.push(lhs.clone());
+                    return false;
+                }
+                // Invalid carry entirely
+                invalid.push(wire.to_string());
+                false
+            }
+        }
+    }
+
+    // Returns false if invalid found
+    fn check_zzz_xor(&self, num: u32, invalid: &mut Vec<String>) -> bool {
+        let z_zz = format!("z{:02}", num);
+        if num == 0 {
+            let res = self.is_xy_xor(num, &z_zz);
+            if !res {
+                invalid.push(z_zz);
+            }
+            return res;
+        } else if num == self.max_z {
+            // Skip the last carry check
+            return true;
+        }
+        match &self.wires.get(&z_zz).unwrap().gate {
+            None => {
+                invalid.push(z_zz);
+                false
+            }
+            Some(vz_zz) => {
+                let gate = &vz_zz.gate_op;
+                if *gate != GateOp::Xor {
+                    invalid.push(z_zz);
+                    return false;
+                }
+                let lhs = &vz_zz.operand_1;
+                let rhs = &vz_zz.operand_2;
+                let valid_xy_lhs = self.is_xy_xor(num, lhs);
+                let valid_xy_rhs = self.is_xy_xor(num, rhs);
+
+                if valid_xy_lhs {
+                    self.is_valid_carry_chain(num, rhs, invalid)
+                } else if valid_xy_rhs {
+                    self.is_valid_carry_chain(num, lhs, invalid)
+                } else {
+                    assert!(!valid_xy_lhs && !valid_xy_rhs);
+                    let mut lhs_vec = Vec::new();
+                    let lhs_carry = self.is_valid_carry_chain(num, lhs, &mut lhs_vec);
+                    if lhs_carry {
+                        invalid.push(rhs.clone());
+                        return false;
+                    }
+                    let mut rhs_vec = Vec::new();
+                    let rhs_carry = self.is_valid_carry_chain(num, rhs, &mut rhs_vec);
+                    if rhs_carry {
+                        invalid.push(lhs.clone());
+                        return false;
+                    } else {
+                        // Both sides are invalid, so just push the whole node
+                        invalid.push(z_zz);
+                    }
+                    false
+                }
+            }
+        }
+    }
+
+    /// Returns the sorted, comma-joined wires that break the ripple-carry adder structure.
+    pub fn find_swaps(&self) -> String {
+        if log_enabled(Level::Debug, module_path!()) {
+            let z = self.simulate_no_mut();
+            let x = self.get_value_from_bit_vector("x");
+            let y = self.get_value_from_bit_vector("y");
+            debug!("x: {x:46b}");
+            debug!("y: {y:46b}");
+            debug!("z: {z:46b}");
+            debug!("c: {:46b}", x.wrapping_add(y));
+        }
+
+        let mut invalid: Vec<String> = Vec::new();
+        for i in 0..=self.max_z {
+            self.check_zzz_xor(i, &mut invalid);
+        }
+        debug!("invalid: {invalid:?}");
+        invalid.sort();
+        invalid.join(",")
+    }
+}
+
+/// Reads the initial wire values, a blank line and then the gates.
+pub fn read_circuit(lines: &[String]) -> Result<Circuit, ParseError> {
+    let mut circuit = Circuit::new();
+    for (i, line) in lines.iter().enumerate() {
+        if !line.is_empty() {
+            let wire = Wire::new(i, line)?;
+            circuit.add_wire(&wire);
+        }
+    }
+
+    // Every gate input has to be driven by some other wire
+    for (i, line) in lines.iter().enumerate() {
+        for operand in line.split_ascii_whitespace().step_by(2).take(2) {
+            if line.contains("->") && !circuit.wires.contains_key(operand) {
+                return Err(ParseError::new(i, line, operand, "Unknown wire"));
+            }
+        }
+    }
+    Ok(circuit)
+}
+
+/// Day 24: Crossed Wires.
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+
+    type Parsed = Circuit;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        read_circuit(lines)
+    }
+
+    fn part1(mut parsed: Self::Parsed) -> Answer {
+        parsed.simulate().into()
+    }
+
+    fn part2(parsed: Self::Parsed) -> Answer {
+        parsed.find_swaps().into()
+    }
+}
+
+#[test]
+fn test_too_many_bits() {
+    let lines = [
+        "x00: 1",
+        "y00: 0",
+        "",
+        format!("x00 AND y00 -> z{}", Value::BITS).as_str(),
+    ]
+    .iter()
+    .map(|l| l.to_string())
+    .collect::<Vec<_>>();
+    let err = read_circuit(&lines).unwrap_err();
+    assert_eq!((err.line, err.column), (4, 16));
+}
+
+#[test]
+fn test_invalid_gate() {
+    let lines = ["x00: 1", "y00: 0", "", "x00 NAND y00 -> z00"];
+    let lines = lines.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+    let err = read_circuit(&lines).unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (4, 5, "NAND"));
+}
+
+#[test]
+fn test_simulate_random() {
+    check(
+        50,
+        |rng| {
+            let bits = 16 + rng.below(40);
+            generate::generate(rng, bits)
+        },
+        |lines| {
+            // Drop gates and clear input bits, which keeps the circuit readable more often than not
+            let mut smaller = shrink_vec(lines);
+            for (i, line) in lines.iter().enumerate() {
+                if let Some(wire) = line.strip_suffix(": 1") {
+                    let mut candidate = lines.clone();
+                    candidate[i] = format!("{wire}: 0");
+                    smaller.push(candidate);
+                }
+            }
+            smaller
+        },
+        |lines| match read_circuit(lines) {
+            Ok(mut circuit) => circuit.simulate_no_mut() == circuit.simulate(),
+            // Shrinking can break the circuit, which isn't a disagreement
+            Err(_) => true,
+        },
+    );
+}
@@ -0,0 +1,29 @@
+// `size` random lock and key schematics, in roughly equal numbers.
+
+use aoc_common::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for _ in 0..size {
+        let heights: Vec<usize> = (0..5).map(|_| rng.below(6)).collect();
+        let is_lock = rng.chance(0.5);
+        // Locks hang down from the top row, and keys stick up from the bottom row
+        let mut schematic: Vec<String> = (0..7)
+            .map(|row| {
+                heights
+                    .iter()
+                    .map(|&h| match is_lock {
+                        true if row <= h => '#',
+                        false if row >= 6 - h => '#',
+                        _ => '.',
+                    })
+                    .collect()
+            })
+            .collect();
+        lines.append(&mut schematic);
+        lines.push(String::new());
+    }
+    // No blank line after the last schematic
+    lines.pop();
+    lines
+}
@@ -0,0 +1,147 @@
+//! Day 25: Code Chronicle. Counts the keys that fit each lock.
+
+pub mod generate;
+
+use aoc_common::{Answer, ParseError, Solution};
+
+const WIDTH: usize = 5;
+const HEIGHT: usize = 7;
+
+type Heights = [i32; WIDTH];
+
+#[derive(Debug)]
+struct KeyOrLock {
+    heights: Heights,
+}
+
+impl KeyOrLock {
+    fn new(heights: Heights) -> Self {
+        KeyOrLock { heights }
+    }
+}
+
+/// The pin heights of every key and lock schematic.
+#[derive(Debug)]
+pub struct Tumblers {
+    keys: Vec<KeyOrLock>,
+    locks: Vec<KeyOrLock>,
+}
+
+impl Tumblers {
+    fn new() -> Self {
+        Tumblers {
+            keys: Vec::new(),
+            locks: Vec::new(),
+        }
+    }
+
+    /// Counts the key and lock pairs that don't overlap in any column.
+    pub fn num_fit(&self) -> u64 {
+        let mut fits = 0;
+
+        for key in &self.keys {
+            for lock in &self.locks {
+                let valid = key
+                    .heights
+                    .iter()
+                    .zip(lock.heights.iter())
+                    .fold(true, |v, (kh, lh)| v && kh + lh <= WIDTH as i32);
+                if valid {
+                    fits += 1;
+                }
+            }
+        }
+        fits
+    }
+}
+
+#[derive(PartialEq)]
+enum Type {
+    Key,
+    Lock,
+    Unknown,
+}
+
+/// Reads the blank-line separated schematics, which are locks when the top row is filled.
+pub fn read_tumblers(lines: &[String]) -> Result<Tumblers, ParseError> {
+    let mut tumblers = Tumblers::new();
+    let mut cur_block: Heights = [-1; WIDTH];
+    let mut cur_type = Type::Unknown;
+    let mut cur_height = 0;
+    for (y, line) in lines.iter().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        if cur_height == 0 && cur_type == Type::Unknown && line == "#####" {
+            // Blocks starting with ##### indicate a lock.
+            cur_type = Type::Lock;
+        } else if cur_height == 0 && cur_type == Type::Unknown && line == "....." {
+            // Blocks starting with ..... indicate a key.
+            cur_type = Type::Key;
+        } else if cur_height == 0 {
+            return Err(ParseError::new(y, line, line, "Unknown Key/Lock type"));
+        }
+
+        if line.len() != WIDTH {
+            return Err(ParseError::new(y, line, line, "Invalid Key/Lock width"));
+        }
+        for (i, c) in line.char_indices() {
+            match c {
+                '#' => cur_block[i] += 1,
+                '.' => {}
+                _ => {
+                    return Err(ParseError::new(
+                        y,
+                        line,
+                        &line[i..i + c.len_utf8()],
+                        "Invalid pin",
+                    ))
+                }
+            }
+        }
+
+        cur_height += 1;
+        if cur_height == HEIGHT {
+            match cur_type {
+                Type::Key => {
+                    let new_key = KeyOrLock::new(cur_block);
+                    tumblers.keys.push(new_key);
+                }
+                Type::Lock => {
+                    let new_lock = KeyOrLock::new(cur_block);
+                    tumblers.locks.push(new_lock);
+                }
+                Type::Unknown => {
+                    panic!("Unknown Key/Lock type");
+                }
+            }
+
+            // Reset everything
+            cur_type = Type::Unknown;
+            cur_height = 0;
+            cur_block = [-1; WIDTH];
+        }
+    }
+    if cur_height != 0 {
+        return Err(ParseError::end_of_input(lines, "Incomplete Key/Lock"));
+    }
+    Ok(tumblers)
+}
+
+/// Day 25: Code Chronicle.
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+
+    type Parsed = Tumblers;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        read_tumblers(lines)
+    }
+
+    fn part1(parsed: Self::Parsed) -> Answer {
+        parsed.num_fit().into()
+    }
+}